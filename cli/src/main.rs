@@ -0,0 +1,172 @@
+//! Runs the simulation headless, e.g.:
+//!
+//! ```text
+//! shorelark example.toml --output best.json
+//! ```
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use lib_neural_network::Network;
+use lib_simulation::{Config, Simulation};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(about = "Evolves birds headless and saves the best brain")]
+struct Args {
+    /// Experiment's configuration, either `.toml` or `.json`
+    config: PathBuf,
+
+    /// Where to write the best network to; `.json` files get the JSON
+    /// format, everything else gets the binary checkpoint format.
+    ///
+    /// Configuration the run actually used (defaults included) gets written
+    /// next to it, with the `.config.toml` extension.
+    #[arg(short, long, default_value = "best.nn")]
+    output: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    let config = load_config(&args.config)?;
+    let config_path = args.output.with_extension("config.toml");
+
+    fs::write(&config_path, config.to_toml())
+        .with_context(|| format!("couldn't write config to {}", config_path.display()))?;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    let mut simulation = Simulation::try_random_with(&mut rng, &config)?;
+    let mut best: Option<(f32, Network)> = None;
+
+    for _ in 0..config.generations {
+        let statistics = simulation.train(&mut rng);
+
+        println!("{}", statistics);
+
+        if best
+            .as_ref()
+            .is_none_or(|(fitness, _)| statistics.max_fitness() > *fitness)
+        {
+            if let Some(champion) = simulation.champion() {
+                best = Some((statistics.max_fitness(), champion.clone()));
+            }
+        }
+    }
+
+    let (fitness, network) = best.context("no generation has been run")?;
+
+    save_network(&args.output, &network)?;
+
+    println!(
+        "saved best network (fitness {:.2}) to {}",
+        fitness,
+        args.output.display()
+    );
+
+    Ok(())
+}
+
+fn load_config(path: &Path) -> Result<Config> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("couldn't read config from {}", path.display()))?;
+
+    let config = parse_config(&contents, extension(path))
+        .with_context(|| format!("couldn't load config from {}", path.display()))?;
+
+    Ok(config)
+}
+
+fn parse_config(contents: &str, extension: &str) -> Result<Config> {
+    match extension {
+        "toml" => Ok(Config::from_toml(contents)?),
+
+        "json" => {
+            let config: Config = serde_json::from_str(contents)?;
+
+            config.validate()?;
+
+            Ok(config)
+        }
+
+        _ => bail!(
+            "unsupported format `{}`, expected .toml or .json",
+            extension
+        ),
+    }
+}
+
+fn save_network(path: &Path, network: &Network) -> Result<()> {
+    let file = File::create(path).with_context(|| format!("couldn't create {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+
+    let result = if extension(path) == "json" {
+        serde_json::to_writer_pretty(&mut writer, network).map_err(Into::into)
+    } else {
+        network.write_to(&mut writer)
+    };
+
+    result.with_context(|| format!("couldn't write network to {}", path.display()))
+}
+
+fn extension(path: &Path) -> &str {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let config = parse_config(include_str!("../example.toml"), "toml").unwrap();
+
+        // Example spells out the defaults, apart from the seed
+        assert_eq!(config.seed, 42);
+        assert_eq!(Config { seed: 0, ..config }, Config::default());
+    }
+
+    #[test]
+    fn defaults() {
+        let toml = parse_config("seed = 7", "toml").unwrap();
+        let json = parse_config(r#"{ "world": { "animals": 10 } }"#, "json").unwrap();
+
+        assert_eq!(toml.seed, 7);
+        assert_eq!(toml.world, Config::default().world);
+        assert_eq!(json.world.animals, 10);
+        assert_eq!(json.world.foods, Config::default().world.foods);
+    }
+
+    #[test]
+    fn invalid() {
+        let error =
+            |contents, extension| format!("{:#}", parse_config(contents, extension).unwrap_err());
+
+        assert!(error("[eye]\ncells = 0", "toml").contains("`eye.cells`"));
+        assert!(error("[eye]\ncels = 9", "toml").contains("unknown field `cels`"));
+        assert!(error(r#"{ "ga": { "elitism": 100 } }"#, "json").contains("`ga.elitism`"));
+        assert!(error(
+            "[world]\nanimals = 5\n[ga]\nelitism = 5\nselection = { type = \"stochastic_universal_sampling\" }",
+            "toml"
+        )
+        .contains("must be lower than world.animals"));
+        assert!(error(r#"{ "seed": -1 }"#, "json").contains("invalid value"));
+        assert!(error("", "yaml").contains("unsupported format `yaml`"));
+    }
+}
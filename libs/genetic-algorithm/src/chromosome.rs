@@ -0,0 +1,153 @@
+use std::ops::Index;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chromosome {
+    genes: Vec<f32>,
+
+    /// Mutation step size, for self-adaptive mutation
+    step_size: Option<f32>,
+}
+
+impl Chromosome {
+    pub fn with_step_size(mut self, step_size: f32) -> Self {
+        self.step_size = Some(step_size);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.genes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+        self.genes.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.genes.iter_mut()
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        &self.genes
+    }
+
+    pub fn step_size(&self) -> Option<f32> {
+        self.step_size
+    }
+
+    pub fn set_step_size(&mut self, step_size: f32) {
+        self.step_size = Some(step_size);
+    }
+
+    /// Children inherit geometric mean of their parents' step sizes, since
+    /// crossover methods only ever look at genes.
+    pub(crate) fn inherit_step_size(&mut self, parent_a: &Self, parent_b: &Self) {
+        self.step_size = match (parent_a.step_size, parent_b.step_size) {
+            (Some(a), Some(b)) => Some((a * b).sqrt()),
+            (step_size, None) | (None, step_size) => step_size,
+        };
+    }
+}
+
+impl Index<usize> for Chromosome {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.genes[index]
+    }
+}
+
+impl FromIterator<f32> for Chromosome {
+    fn from_iter<T: IntoIterator<Item = f32>>(iter: T) -> Self {
+        Self {
+            genes: iter.into_iter().collect(),
+            step_size: None,
+        }
+    }
+}
+
+impl IntoIterator for Chromosome {
+    type Item = f32;
+    type IntoIter = std::vec::IntoIter<f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.genes.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chromosome() -> Chromosome {
+        Chromosome {
+            genes: vec![3.0, 1.0, 2.0],
+            step_size: None,
+        }
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(chromosome().len(), 3);
+        assert!(!chromosome().is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let genes: Vec<_> = chromosome().iter().copied().collect();
+
+        assert_eq!(genes, [3.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut chromosome = chromosome();
+
+        chromosome.iter_mut().for_each(|gene| *gene *= 10.0);
+
+        assert_eq!(chromosome.as_slice(), [30.0, 10.0, 20.0]);
+    }
+
+    #[test]
+    fn inherit_step_size() {
+        let mut child = chromosome();
+
+        child.inherit_step_size(&chromosome(), &chromosome());
+        assert_eq!(child.step_size(), None);
+
+        child.inherit_step_size(&chromosome().with_step_size(0.5), &chromosome());
+        assert_eq!(child.step_size(), Some(0.5));
+
+        child.inherit_step_size(
+            &chromosome().with_step_size(0.5),
+            &chromosome().with_step_size(2.0),
+        );
+        assert_eq!(child.step_size(), Some(1.0));
+    }
+
+    #[test]
+    fn index() {
+        let chromosome = chromosome();
+
+        assert_eq!(chromosome[0], 3.0);
+        assert_eq!(chromosome[1], 1.0);
+        assert_eq!(chromosome[2], 2.0);
+    }
+
+    #[test]
+    fn from_iterator() {
+        let chromosome: Chromosome = vec![3.0, 1.0, 2.0].into_iter().collect();
+
+        assert_eq!(chromosome, self::chromosome());
+    }
+
+    #[test]
+    fn into_iterator() {
+        let genes: Vec<_> = chromosome().into_iter().collect();
+
+        assert_eq!(genes, [3.0, 1.0, 2.0]);
+    }
+}
@@ -0,0 +1,439 @@
+use crate::Chromosome;
+use lib_neural_network::LayerTopology;
+use rand::{Rng, RngCore};
+use std::iter;
+
+pub trait CrossoverMethod {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome;
+}
+
+impl<T> CrossoverMethod for Box<T>
+where
+    T: CrossoverMethod + ?Sized,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        (**self).crossover(rng, parent_a, parent_b)
+    }
+}
+
+/// Picks each gene from either of the parents with equal probability.
+#[derive(Clone, Debug, Default)]
+pub struct UniformCrossover;
+
+impl CrossoverMethod for UniformCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
+            .collect()
+    }
+}
+
+/// Takes genes before a random point from `parent_a` and the rest from
+/// `parent_b`.
+#[derive(Clone, Debug, Default)]
+pub struct SinglePointCrossover;
+
+impl CrossoverMethod for SinglePointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let point = rng.gen_range(0..=parent_a.len());
+
+        parent_a
+            .iter()
+            .take(point)
+            .chain(parent_b.iter().skip(point))
+            .copied()
+            .collect()
+    }
+}
+
+/// Takes genes between two random points from `parent_b` and the rest from
+/// `parent_a`.
+#[derive(Clone, Debug, Default)]
+pub struct TwoPointCrossover;
+
+impl CrossoverMethod for TwoPointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let a = rng.gen_range(0..=parent_a.len());
+        let b = rng.gen_range(0..=parent_a.len());
+        let range = a.min(b)..a.max(b);
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .enumerate()
+            .map(|(idx, (&a, &b))| if range.contains(&idx) { b } else { a })
+            .collect()
+    }
+}
+
+/// Blend crossover (BLX-α) - draws each gene uniformly from the range spanned
+/// by the parents' genes, extended on both sides by `alpha` times its width.
+#[derive(Clone, Debug)]
+pub struct BlendCrossover {
+    /// - 0.0 = genes stay between the parents' genes
+    /// - 0.5 = genes can land up to half the distance outside of them
+    alpha: f32,
+}
+
+impl BlendCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!(alpha >= 0.0);
+
+        Self { alpha }
+    }
+}
+
+impl Default for BlendCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CrossoverMethod for BlendCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let extent = self.alpha * (a - b).abs();
+                let min = a.min(b) - extent;
+                let max = a.max(b) + extent;
+
+                min + (max - min) * rng.gen::<f32>()
+            })
+            .collect()
+    }
+}
+
+/// Simulated binary crossover (SBX) - spreads each gene around the parents'
+/// mean the way single-point crossover spreads bits of binary chromosomes.
+#[derive(Clone, Debug)]
+pub struct SimulatedBinaryCrossover {
+    /// Distribution index:
+    /// - small values (e.g. 2.0) = children can land far from the parents
+    /// - large values (e.g. 20.0) = children stay close to the parents
+    eta: f32,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(eta: f32) -> Self {
+        assert!(eta >= 0.0);
+
+        Self { eta }
+    }
+}
+
+impl Default for SimulatedBinaryCrossover {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl CrossoverMethod for SimulatedBinaryCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let u = rng.gen::<f32>();
+
+                let beta = if u <= 0.5 {
+                    (2.0 * u).powf(exponent)
+                } else {
+                    (1.0 / (2.0 * (1.0 - u))).powf(exponent)
+                };
+
+                0.5 * ((1.0 + beta) * a + (1.0 - beta) * b)
+            })
+            .collect()
+    }
+}
+
+/// Picks whole neurons - a bias together with its weights - from either of the
+/// parents with equal probability, so that they don't get torn apart.
+///
+/// Chromosomes must follow the layout of [`lib_neural_network::Network::to_weights()`]
+/// for the topology given here.
+#[derive(Clone, Debug)]
+pub struct NeuronCrossover {
+    /// Number of genes of each consecutive neuron
+    neurons: Vec<usize>,
+}
+
+impl NeuronCrossover {
+    pub fn new(topology: &[LayerTopology]) -> Self {
+        LayerTopology::validate(topology)
+            .unwrap_or_else(|err| panic!("couldn't create neuron crossover: {}", err));
+
+        let neurons = topology[1..]
+            .iter()
+            .flat_map(|layer| iter::repeat_n(layer.input_neurons + 1, layer.output_neurons))
+            .collect();
+
+        Self { neurons }
+    }
+}
+
+impl CrossoverMethod for NeuronCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+        assert_eq!(parent_a.len(), self.neurons.iter().sum::<usize>());
+
+        let mut offset = 0;
+        let mut genes = Vec::with_capacity(parent_a.len());
+
+        for &len in &self.neurons {
+            let parent = if rng.gen_bool(0.5) {
+                parent_a
+            } else {
+                parent_b
+            };
+
+            genes.extend_from_slice(&parent.as_slice()[offset..offset + len]);
+            offset += len;
+        }
+
+        genes.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use lib_neural_network::{Activation, Network};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn uniform() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=100).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=100).map(|n| -n as f32).collect();
+        let child = UniformCrossover.crossover(&mut rng, &parent_a, &parent_b);
+
+        // Number of genes different between `child` and `parent_a`
+        let diff_a = child
+            .iter()
+            .zip(parent_a.iter())
+            .filter(|(c, p)| c != p)
+            .count();
+
+        // Number of genes different between `child` and `parent_b`
+        let diff_b = child
+            .iter()
+            .zip(parent_b.iter())
+            .filter(|(c, p)| c != p)
+            .count();
+
+        assert_eq!(child.len(), 100);
+        assert_eq!(diff_a + diff_b, 100);
+        assert_eq!(diff_a, 49);
+    }
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (1..=100).map(|n| n as f32).collect();
+        let parent_b = (1..=100).map(|n| -n as f32).collect();
+
+        (parent_a, parent_b)
+    }
+
+    fn offspring(method: &dyn CrossoverMethod, seed: u64) -> Chromosome {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let (parent_a, parent_b) = parents();
+
+        method.crossover(&mut rng, &parent_a, &parent_b)
+    }
+
+    /// Every operator must preserve the length and give the same child for
+    /// the same seed.
+    fn assert_deterministic(method: &dyn CrossoverMethod) {
+        for seed in 0..10 {
+            let child = offspring(method, seed);
+
+            assert_eq!(child.len(), 100);
+            assert_eq!(child, offspring(method, seed));
+        }
+    }
+
+    /// Returns at which indices the child's genes come from `parent_b`.
+    fn genes_from_b(child: &Chromosome) -> Vec<usize> {
+        child
+            .iter()
+            .enumerate()
+            .filter(|(_, gene)| **gene < 0.0)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    #[test]
+    fn single_point() {
+        assert_deterministic(&SinglePointCrossover);
+
+        for seed in 0..10 {
+            let from_b = genes_from_b(&offspring(&SinglePointCrossover, seed));
+
+            // Genes from `parent_b`, if any, form the suffix
+            if let Some(&first) = from_b.first() {
+                assert_eq!(from_b, (first..100).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn two_point() {
+        assert_deterministic(&TwoPointCrossover);
+
+        for seed in 0..10 {
+            let from_b = genes_from_b(&offspring(&TwoPointCrossover, seed));
+
+            // Genes from `parent_b`, if any, form a single contiguous run
+            if let (Some(&first), Some(&last)) = (from_b.first(), from_b.last()) {
+                assert_eq!(from_b, (first..=last).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn blend() {
+        assert_deterministic(&BlendCrossover::default());
+
+        for alpha in [0.0, 0.5] {
+            let child = offspring(&BlendCrossover::new(alpha), 0);
+
+            for (idx, &gene) in child.iter().enumerate() {
+                let n = (idx + 1) as f32;
+                let extent = n * (1.0 + 2.0 * alpha);
+
+                assert!((-extent..=extent).contains(&gene), "{} at #{}", gene, idx);
+            }
+        }
+
+        // Identical parents have nothing to blend
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent, _) = parents();
+        let child = BlendCrossover::default().crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+
+    #[test]
+    fn simulated_binary() {
+        assert_deterministic(&SimulatedBinaryCrossover::default());
+
+        // Identical parents have nothing to recombine
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent, _) = parents();
+        let child = SimulatedBinaryCrossover::default().crossover(&mut rng, &parent, &parent);
+
+        assert_relative_eq!(child.as_slice(), parent.as_slice(), max_relative = 1e-6);
+
+        // Larger `eta` keeps children closer to the parents
+        let spread = |eta| {
+            let child = offspring(&SimulatedBinaryCrossover::new(eta), 0);
+
+            child
+                .iter()
+                .enumerate()
+                .map(|(idx, gene)| (gene.abs() - (idx + 1) as f32).abs())
+                .sum::<f32>()
+        };
+
+        assert!(spread(20.0) < spread(2.0));
+    }
+
+    #[test]
+    fn neuron() {
+        let topology = Network::builder()
+            .input(3)
+            .hidden(4, Activation::Relu)
+            .output(2, Activation::Tanh)
+            .topology()
+            .to_vec();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a = Chromosome::from(&Network::random_seeded(0, &topology));
+        let parent_b = Chromosome::from(&Network::random_seeded(1, &topology));
+        let method = NeuronCrossover::new(&topology);
+        let child = method.crossover(&mut rng.clone(), &parent_a, &parent_b);
+
+        assert_eq!(child.len(), 26);
+        assert_eq!(child, method.crossover(&mut rng, &parent_a, &parent_b));
+
+        // 4 neurons of 3 weights + bias, then 2 neurons of 4 weights + bias
+        let chunks = [0..4, 4..8, 8..12, 12..16, 16..21, 21..26];
+        let mut from_a = 0;
+
+        for chunk in chunks {
+            let genes = &child.as_slice()[chunk.clone()];
+
+            if genes == &parent_a.as_slice()[chunk.clone()] {
+                from_a += 1;
+            } else {
+                assert_eq!(genes, &parent_b.as_slice()[chunk]);
+            }
+        }
+
+        assert!(from_a > 0 && from_a < 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "couldn't create neuron crossover")]
+    fn neuron_with_invalid_topology() {
+        NeuronCrossover::new(&[LayerTopology::default()]);
+    }
+}
@@ -0,0 +1,281 @@
+mod chromosome;
+mod crossover;
+mod individual;
+mod mutation;
+mod network;
+mod selection;
+mod statistics;
+
+pub use self::{
+    chromosome::*, crossover::*, individual::*, mutation::*, selection::*, statistics::*,
+};
+use rand::RngCore;
+
+pub struct GeneticAlgorithm<S> {
+    selection_method: S,
+    crossover_method: Box<dyn CrossoverMethod>,
+    mutation_method: Box<dyn MutationMethod>,
+    elitism: usize,
+
+    /// Fitness of the fitter parent of each child from the previous call to
+    /// [`GeneticAlgorithm::evolve()`]
+    parents_fitness: Vec<f32>,
+}
+
+impl<S> GeneticAlgorithm<S>
+where
+    S: SelectionMethod,
+{
+    pub fn new(
+        selection_method: S,
+        crossover_method: impl CrossoverMethod + 'static,
+        mutation_method: impl MutationMethod + 'static,
+    ) -> Self {
+        Self {
+            selection_method,
+            crossover_method: Box::new(crossover_method),
+            mutation_method: Box::new(mutation_method),
+            elitism: 0,
+            parents_fitness: Vec::new(),
+        }
+    }
+
+    /// Copies `elitism` fittest individuals into the next generation as they
+    /// are, without crossover or mutation.
+    pub fn with_elitism(mut self, elitism: usize) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    /// Produces the next generation, of the same size as `population`.
+    ///
+    /// To let adaptive mutation methods know how well they did, pass the
+    /// returned individuals back (once evaluated) in the same order.
+    pub fn evolve<I>(&mut self, rng: &mut dyn RngCore, population: &[I]) -> Vec<I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let elitism = self.elitism.min(population.len());
+        let children = &population[elitism..];
+
+        if !children.is_empty() && children.len() == self.parents_fitness.len() {
+            let successes = children
+                .iter()
+                .zip(&self.parents_fitness)
+                .filter(|(child, &parents_fitness)| child.fitness() > parents_fitness)
+                .count();
+
+            self.mutation_method
+                .adapt(successes as f32 / children.len() as f32);
+        }
+
+        let elites = selection::sorted_by_fitness(population)
+            .into_iter()
+            .rev()
+            .take(elitism)
+            .map(|individual| I::create(individual.chromosome().clone()));
+
+        let parents =
+            self.selection_method
+                .select_many(rng, population, 2 * (population.len() - elitism));
+
+        self.parents_fitness = parents
+            .chunks_exact(2)
+            .map(|parents| parents[0].fitness().max(parents[1].fitness()))
+            .collect();
+
+        let children: Vec<_> = parents
+            .chunks_exact(2)
+            .map(|parents| {
+                let (parent_a, parent_b) = (parents[0].chromosome(), parents[1].chromosome());
+                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+
+                child.inherit_step_size(parent_a, parent_b);
+                self.mutation_method.mutate(rng, &mut child);
+
+                I::create(child)
+            })
+            .collect();
+
+        elites.chain(children).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub(crate) enum TestIndividual {
+        /// For tests that require access to the chromosome
+        WithChromosome { chromosome: Chromosome },
+
+        /// For tests that don't require access to the chromosome
+        WithFitness { fitness: f32 },
+    }
+
+    impl TestIndividual {
+        pub(crate) fn new(fitness: f32) -> Self {
+            Self::WithFitness { fitness }
+        }
+    }
+
+    impl Individual for TestIndividual {
+        fn create(chromosome: Chromosome) -> Self {
+            Self::WithChromosome { chromosome }
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            match self {
+                Self::WithChromosome { chromosome } => chromosome,
+
+                Self::WithFitness { .. } => {
+                    panic!("not supported for TestIndividual::WithFitness")
+                }
+            }
+        }
+
+        fn fitness(&self) -> f32 {
+            match self {
+                Self::WithChromosome { chromosome } => chromosome.iter().sum(),
+                Self::WithFitness { fitness } => *fitness,
+            }
+        }
+    }
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    #[test]
+    fn evolve() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.5, 0.5),
+        );
+
+        let mut population = vec![
+            individual(&[0.0, 0.0, 0.0]),
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 1.0]),
+            individual(&[1.0, 2.0, 4.0]),
+        ];
+
+        let avg_fitness = |population: &[TestIndividual]| {
+            population.iter().map(|i| i.fitness()).sum::<f32>() / population.len() as f32
+        };
+
+        let initial = avg_fitness(&population);
+
+        for _ in 0..10 {
+            population = ga.evolve(&mut rng, &population);
+        }
+
+        assert_eq!(population.len(), 4);
+        assert!(avg_fitness(&population) > initial);
+    }
+
+    #[test]
+    fn evolve_with_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2),
+            UniformCrossover,
+            GaussianMutation::new(1.0, 0.5),
+        )
+        .with_elitism(2);
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]),
+            individual(&[1.0, 2.0, 4.0]),
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 1.0]),
+        ];
+
+        let next = ga.evolve(&mut rng, &population);
+
+        assert_eq!(next.len(), 4);
+        assert_eq!(next[0], population[1]);
+        assert_eq!(next[1], population[3]);
+
+        // Everyone else goes through mutation, which always changes genes
+        assert!(!next[2..].contains(&population[1]));
+    }
+
+    /// Adds `delta` to every gene and records success rates it's told about.
+    struct ShiftMutation {
+        delta: f32,
+        success_rates: Rc<RefCell<Vec<f32>>>,
+    }
+
+    impl MutationMethod for ShiftMutation {
+        fn mutate(&self, _rng: &mut dyn RngCore, child: &mut Chromosome) {
+            child.iter_mut().for_each(|gene| *gene += self.delta);
+        }
+
+        fn adapt(&mut self, success_rate: f32) {
+            self.success_rates.borrow_mut().push(success_rate);
+        }
+    }
+
+    #[test]
+    fn evolve_reports_success_rate() {
+        let success_rates = |delta| {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let recorded = Rc::new(RefCell::new(Vec::new()));
+
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection,
+                UniformCrossover,
+                ShiftMutation {
+                    delta,
+                    success_rates: recorded.clone(),
+                },
+            )
+            .with_elitism(1);
+
+            let mut population = vec![individual(&[1.0, 1.0, 1.0]); 4];
+
+            for _ in 0..3 {
+                population = ga.evolve(&mut rng, &population);
+            }
+
+            let recorded = recorded.borrow().clone();
+            recorded
+        };
+
+        // Nothing gets reported for the first generation, since it has no
+        // parents to compare against
+        assert_eq!(success_rates(1.0), [1.0, 1.0]);
+        assert_eq!(success_rates(-1.0), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn evolve_with_self_adaptive_mutation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            SelfAdaptiveMutation::new(1.0, 0.5),
+        );
+
+        let population = vec![individual(&[1.0, 1.0, 1.0]); 4];
+        let population = ga.evolve(&mut rng, &population);
+
+        // Step sizes get passed on to the next generation
+        assert!(population
+            .iter()
+            .all(|individual| individual.chromosome().step_size().is_some()));
+    }
+}
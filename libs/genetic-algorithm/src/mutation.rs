@@ -0,0 +1,303 @@
+use crate::Chromosome;
+use rand::{Rng, RngCore};
+use rand_distr::StandardNormal;
+
+pub trait MutationMethod {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome);
+
+    /// Called by [`crate::GeneticAlgorithm::evolve()`] with the fraction of
+    /// previous generation's children that turned out fitter than their
+    /// parents.
+    fn adapt(&mut self, _success_rate: f32) {}
+}
+
+impl<T> MutationMethod for Box<T>
+where
+    T: MutationMethod + ?Sized,
+{
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        (**self).mutate(rng, child)
+    }
+
+    fn adapt(&mut self, success_rate: f32) {
+        (**self).adapt(success_rate)
+    }
+}
+
+/// Adds normally-distributed noise to genes.
+#[derive(Clone, Debug)]
+pub struct GaussianMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Standard deviation of the noise added to touched genes:
+    /// - 0.0 = touched genes will not be modified
+    /// - 3.0 = touched genes will be += or -= by up to about nine (3σ)
+    coeff: f32,
+
+    /// See [`GaussianMutation::with_one_fifth_rule()`]
+    one_fifth_rule: Option<f32>,
+}
+
+impl GaussianMutation {
+    pub fn new(chance: f32, coeff: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+
+        Self {
+            chance,
+            coeff,
+            one_fifth_rule: None,
+        }
+    }
+
+    /// Adjusts `coeff` across generations using Rechenberg's 1/5th success
+    /// rule - if more than a fifth of children turn out fitter than their
+    /// parents, `coeff` gets divided by `factor` (to explore further);
+    /// if less, it gets multiplied by it (to fine-tune what we've got).
+    ///
+    /// Typical values of `factor` lie between 0.82 and 1.0.
+    pub fn with_one_fifth_rule(mut self, factor: f32) -> Self {
+        assert!(factor > 0.0 && factor <= 1.0);
+
+        self.one_fifth_rule = Some(factor);
+        self
+    }
+
+    pub fn coeff(&self) -> f32 {
+        self.coeff
+    }
+}
+
+impl MutationMethod for GaussianMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as f64) {
+                *gene += self.coeff * rng.sample::<f32, _>(StandardNormal);
+            }
+        }
+    }
+
+    fn adapt(&mut self, success_rate: f32) {
+        let Some(factor) = self.one_fifth_rule else {
+            return;
+        };
+
+        if success_rate > 0.2 {
+            self.coeff /= factor;
+        } else if success_rate < 0.2 {
+            self.coeff *= factor;
+        }
+    }
+}
+
+/// Replaces genes with values drawn uniformly from `-limit..=limit`.
+#[derive(Clone, Debug)]
+pub struct UniformResetMutation {
+    /// Probability of replacing a gene
+    chance: f32,
+    limit: f32,
+}
+
+impl UniformResetMutation {
+    pub fn new(chance: f32, limit: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(limit >= 0.0);
+
+        Self { chance, limit }
+    }
+}
+
+impl MutationMethod for UniformResetMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as f64) {
+                *gene = rng.gen_range(-self.limit..=self.limit);
+            }
+        }
+    }
+}
+
+/// Gaussian mutation where each chromosome carries its own step size (as in
+/// evolution strategies); the step size itself gets mutated log-normally
+/// before being applied to genes, so step sizes that produce fitter children
+/// get passed on together with them.
+#[derive(Clone, Debug)]
+pub struct SelfAdaptiveMutation {
+    /// Probability of changing a gene
+    chance: f32,
+
+    /// Step size for chromosomes that don't have one yet
+    initial_step_size: f32,
+}
+
+impl SelfAdaptiveMutation {
+    /// Step sizes never shrink below this, so that evolution cannot stall
+    const MIN_STEP_SIZE: f32 = 1e-5;
+
+    pub fn new(chance: f32, initial_step_size: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(initial_step_size > 0.0);
+
+        Self {
+            chance,
+            initial_step_size,
+        }
+    }
+}
+
+impl MutationMethod for SelfAdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        let learning_rate = 1.0 / (child.len().max(1) as f32).sqrt();
+
+        let step_size = child.step_size().unwrap_or(self.initial_step_size)
+            * (learning_rate * rng.sample::<f32, _>(StandardNormal)).exp();
+
+        let step_size = step_size.max(Self::MIN_STEP_SIZE);
+
+        child.set_step_size(step_size);
+
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as f64) {
+                *gene += step_size * rng.sample::<f32, _>(StandardNormal);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, coeff: f32) -> Vec<f32> {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        GaussianMutation::new(chance, coeff).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance() {
+        assert_eq!(actual(0.0, 0.5), [1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn zero_coeff() {
+        assert_eq!(actual(1.0, 0.0), [1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn full_chance() {
+        let actual = actual(1.0, 0.5);
+
+        assert!(actual
+            .iter()
+            .zip([1.0, 2.0, 3.0, 4.0, 5.0])
+            .all(|(actual, original)| *actual != original));
+    }
+
+    #[test]
+    fn deterministic() {
+        assert_eq!(actual(0.5, 0.5), actual(0.5, 0.5));
+    }
+
+    /// Returns which fraction of genes got changed by mutating a large
+    /// chromosome.
+    fn mutated_fraction(method: &dyn MutationMethod) -> f32 {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child: Chromosome = vec![0.0; 10_000].into_iter().collect();
+
+        method.mutate(&mut rng, &mut child);
+
+        child.iter().filter(|gene| **gene != 0.0).count() as f32 / child.len() as f32
+    }
+
+    #[test]
+    fn mutated_fraction_matches_chance() {
+        for chance in [0.0, 0.05, 0.3, 0.7, 1.0] {
+            let gaussian = mutated_fraction(&GaussianMutation::new(chance, 0.5));
+            let reset = mutated_fraction(&UniformResetMutation::new(chance, 1.0));
+            let adaptive = mutated_fraction(&SelfAdaptiveMutation::new(chance, 0.5));
+
+            assert_relative_eq!(gaussian, chance, epsilon = 0.015);
+            assert_relative_eq!(reset, chance, epsilon = 0.015);
+            assert_relative_eq!(adaptive, chance, epsilon = 0.015);
+        }
+    }
+
+    #[test]
+    fn uniform_reset() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child: Chromosome = vec![100.0; 1000].into_iter().collect();
+
+        UniformResetMutation::new(1.0, 2.0).mutate(&mut rng, &mut child);
+
+        assert!(child.iter().all(|gene| (-2.0..=2.0).contains(gene)));
+    }
+
+    #[test]
+    fn self_adaptive() {
+        let mutated = |step_size| {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let mut child = vec![0.0; 1000]
+                .into_iter()
+                .collect::<Chromosome>()
+                .with_step_size(step_size);
+
+            SelfAdaptiveMutation::new(1.0, 1.0).mutate(&mut rng, &mut child);
+            child
+        };
+
+        let small = mutated(0.01);
+        let large = mutated(1.0);
+
+        // Step size gets perturbed, but stays in the same ballpark
+        let step_size = small.step_size().unwrap();
+
+        assert!(step_size != 0.01);
+        assert!((0.005..0.02).contains(&step_size));
+
+        // ... and it's what drives the magnitude of changes
+        let magnitude = |child: &Chromosome| child.iter().map(|gene| gene.abs()).sum::<f32>();
+
+        assert!(magnitude(&small) * 10.0 < magnitude(&large));
+    }
+
+    #[test]
+    fn self_adaptive_without_step_size() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child: Chromosome = vec![0.0; 100].into_iter().collect();
+
+        SelfAdaptiveMutation::new(0.5, 0.1).mutate(&mut rng, &mut child);
+
+        assert!(child.step_size().is_some());
+    }
+
+    #[test]
+    fn one_fifth_rule() {
+        let mut method = GaussianMutation::new(0.5, 1.0).with_one_fifth_rule(0.5);
+
+        method.adapt(0.5);
+        assert_eq!(method.coeff(), 2.0);
+
+        method.adapt(0.2);
+        assert_eq!(method.coeff(), 2.0);
+
+        method.adapt(0.0);
+        method.adapt(0.1);
+        assert_eq!(method.coeff(), 0.5);
+
+        // Without the rule, `coeff` stays put
+        let mut method = GaussianMutation::new(0.5, 1.0);
+
+        method.adapt(0.0);
+        assert_eq!(method.coeff(), 1.0);
+    }
+}
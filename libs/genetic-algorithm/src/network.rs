@@ -0,0 +1,60 @@
+//! Conversions between [`Network`]s and [`Chromosome`]s, so that brains can be
+//! evolved.
+
+use crate::Chromosome;
+use lib_neural_network::{LayerTopology, Network, NetworkError};
+
+impl From<&Network> for Chromosome {
+    fn from(network: &Network) -> Self {
+        network.weights().collect()
+    }
+}
+
+impl Chromosome {
+    pub fn to_network(&self, topology: &[LayerTopology]) -> Result<Network, NetworkError> {
+        Network::from_weights(topology, self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_neural_network::Activation;
+
+    #[test]
+    fn roundtrip() {
+        let network = Network::builder()
+            .input(3)
+            .hidden(4, Activation::Relu)
+            .output(2, Activation::Tanh)
+            .random_seeded(0)
+            .unwrap();
+
+        let chromosome = Chromosome::from(&network);
+
+        assert_eq!(chromosome.as_slice(), network.to_weights());
+
+        let restored = chromosome.to_network(&network.topology()).unwrap();
+
+        assert_eq!(restored.to_weights(), network.to_weights());
+    }
+
+    #[test]
+    fn mismatched_topology() {
+        let chromosome: Chromosome = vec![0.0; 3].into_iter().collect();
+
+        let topology = Network::builder()
+            .input(3)
+            .output(2, Activation::Tanh)
+            .topology()
+            .to_vec();
+
+        assert_eq!(
+            chromosome.to_network(&topology).unwrap_err(),
+            NetworkError::WeightsMismatch {
+                expected: 8,
+                actual: 3,
+            }
+        );
+    }
+}
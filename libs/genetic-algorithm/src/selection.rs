@@ -0,0 +1,382 @@
+use crate::Individual;
+use rand::distributions::{Distribution, WeightedError, WeightedIndex};
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+pub trait SelectionMethod {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual;
+
+    /// Selects `count` individuals at once; methods that can do that better
+    /// than by calling [`SelectionMethod::select()`] in a loop (e.g. because
+    /// they have to sort the population first) override this function.
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        (0..count).map(|_| self.select(rng, population)).collect()
+    }
+}
+
+/// Fitness-proportionate selection; fitness must not be negative.
+///
+/// If all of the individuals have zero fitness, picks uniformly.
+#[derive(Clone, Debug, Default)]
+pub struct RouletteWheelSelection;
+
+impl SelectionMethod for RouletteWheelSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        match population.choose_weighted(rng, |individual| individual.fitness()) {
+            Ok(individual) => individual,
+            Err(WeightedError::AllWeightsZero) => population.choose(rng).unwrap(),
+            Err(err) => panic!("couldn't select individual: {}", err),
+        }
+    }
+}
+
+/// Fitness-proportionate selection that picks all individuals at once using
+/// evenly-spaced pointers, which gives weaker individuals a fairer chance than
+/// [`RouletteWheelSelection`] does.
+#[derive(Clone, Debug, Default)]
+pub struct StochasticUniversalSampling;
+
+impl SelectionMethod for StochasticUniversalSampling {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        // Otherwise the step would be infinite
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let total = population.iter().map(|i| i.fitness()).sum::<f32>();
+
+        if total <= 0.0 {
+            return (0..count)
+                .map(|_| population.choose(rng).unwrap())
+                .collect();
+        }
+
+        let step = total / count as f32;
+        let mut pointer = rng.gen_range(0.0..step);
+        let mut cumulative = 0.0;
+        let mut selected = Vec::with_capacity(count);
+
+        for individual in population {
+            cumulative += individual.fitness();
+
+            while pointer < cumulative && selected.len() < count {
+                selected.push(individual);
+                pointer += step;
+            }
+        }
+
+        // Floating-point errors might leave the last pointer(s) hanging
+        while selected.len() < count {
+            selected.push(population.last().unwrap());
+        }
+
+        // Selected individuals are grouped, so pairing neighbours as parents
+        // would mostly breed individuals with themselves
+        selected.shuffle(rng);
+        selected
+    }
+}
+
+/// Picks `size` random individuals and returns the fittest one.
+#[derive(Clone, Debug)]
+pub struct TournamentSelection {
+    size: usize,
+}
+
+impl TournamentSelection {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0);
+
+        Self { size }
+    }
+}
+
+impl SelectionMethod for TournamentSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        (0..self.size)
+            .map(|_| population.choose(rng).expect("got an empty population"))
+            .max_by(|a, b| a.fitness().total_cmp(&b.fitness()))
+            .unwrap()
+    }
+}
+
+/// Picks individuals with probability proportional to their rank - the
+/// weakest individual has weight of 1, the fittest one has weight of
+/// `population.len()`; unlike [`RouletteWheelSelection`] it works for
+/// negative fitness, too.
+#[derive(Clone, Debug, Default)]
+pub struct RankSelection;
+
+impl SelectionMethod for RankSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        let ranked = sorted_by_fitness(population);
+
+        // Built once for all of the draws, each of which is then O(log n)
+        let ranks = WeightedIndex::new(1..=ranked.len()).expect("got an empty population");
+
+        (0..count).map(|_| ranked[ranks.sample(rng)]).collect()
+    }
+}
+
+/// Picks uniformly among the fittest `proportion` of the population.
+#[derive(Clone, Debug)]
+pub struct TruncationSelection {
+    proportion: f32,
+}
+
+impl TruncationSelection {
+    pub fn new(proportion: f32) -> Self {
+        assert!(proportion > 0.0 && proportion <= 1.0);
+
+        Self { proportion }
+    }
+}
+
+impl SelectionMethod for TruncationSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        let ranked = sorted_by_fitness(population);
+        let survivors = ((ranked.len() as f32 * self.proportion).ceil() as usize).max(1);
+        let survivors = &ranked[ranked.len() - survivors..];
+
+        (0..count)
+            .map(|_| *survivors.choose(rng).expect("got an empty population"))
+            .collect()
+    }
+}
+
+/// Returns individuals ordered from the weakest to the fittest one.
+pub(crate) fn sorted_by_fitness<I>(population: &[I]) -> Vec<&I>
+where
+    I: Individual,
+{
+    let mut sorted: Vec<_> = population.iter().collect();
+
+    sorted.sort_by(|a, b| a.fitness().total_cmp(&b.fitness()));
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestIndividual;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    const SAMPLES: usize = 10_000;
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    /// Returns how often each fitness got selected, in the order of fitness.
+    fn frequencies(selected: Vec<&TestIndividual>) -> Vec<f32> {
+        let mut histogram = BTreeMap::from_iter([(1, 0), (2, 0), (3, 0), (4, 0)]);
+
+        for individual in &selected {
+            *histogram.get_mut(&(individual.fitness() as i32)).unwrap() += 1;
+        }
+
+        histogram
+            .values()
+            .map(|&count| count as f32 / selected.len() as f32)
+            .collect()
+    }
+
+    fn assert_frequencies(method: impl SelectionMethod, expected: [f32; 4]) {
+        let population = population();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let selected = (0..SAMPLES)
+            .map(|_| method.select(&mut rng, &population))
+            .collect();
+
+        let actual = frequencies(selected);
+
+        assert_relative_eq!(actual.as_slice(), expected.as_ref(), epsilon = 0.015);
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let selected = method.select_many(&mut rng, &population, SAMPLES);
+
+        assert_eq!(selected.len(), SAMPLES);
+
+        let actual = frequencies(selected);
+
+        assert_relative_eq!(actual.as_slice(), expected.as_ref(), epsilon = 0.015);
+    }
+
+    #[test]
+    fn roulette_wheel() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+        let mut actual_histogram = BTreeMap::new();
+
+        for _ in 0..1000 {
+            let fitness = RouletteWheelSelection
+                .select(&mut rng, &population)
+                .fitness() as i32;
+
+            *actual_histogram.entry(fitness).or_insert(0) += 1;
+        }
+
+        let expected_histogram = BTreeMap::from_iter([
+            // (fitness, how many times this fitness has been chosen)
+            (1, 98),
+            (2, 202),
+            (3, 278),
+            (4, 422),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+
+        assert_frequencies(RouletteWheelSelection, [0.1, 0.2, 0.3, 0.4]);
+    }
+
+    #[test]
+    fn roulette_wheel_with_zero_fitness() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = vec![TestIndividual::new(0.0), TestIndividual::new(0.0)];
+
+        assert_eq!(
+            RouletteWheelSelection
+                .select(&mut rng, &population)
+                .fitness(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn stochastic_universal_sampling() {
+        assert_frequencies(StochasticUniversalSampling, [0.1, 0.2, 0.3, 0.4]);
+
+        // With as many pointers as individuals' total fitness, each individual
+        // gets selected exactly as many times as its fitness
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+        let selected = StochasticUniversalSampling.select_many(&mut rng, &population, 10);
+
+        let actual = frequencies(selected);
+
+        assert_relative_eq!(actual.as_slice(), [0.1, 0.2, 0.3, 0.4].as_ref());
+    }
+
+    #[test]
+    fn stochastic_universal_sampling_of_none() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+
+        assert!(StochasticUniversalSampling
+            .select_many(&mut rng, &population, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn tournament() {
+        // Probability of the i-th weakest individual winning is
+        // (i/n)^k - ((i-1)/n)^k
+        assert_frequencies(TournamentSelection::new(1), [0.25, 0.25, 0.25, 0.25]);
+        assert_frequencies(
+            TournamentSelection::new(2),
+            [1.0 / 16.0, 3.0 / 16.0, 5.0 / 16.0, 7.0 / 16.0],
+        );
+        assert_frequencies(
+            TournamentSelection::new(3),
+            [1.0 / 64.0, 7.0 / 64.0, 19.0 / 64.0, 37.0 / 64.0],
+        );
+    }
+
+    #[test]
+    fn rank() {
+        assert_frequencies(RankSelection, [0.1, 0.2, 0.3, 0.4]);
+
+        // Rank doesn't care about the actual fitness values
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(-100.0),
+            TestIndividual::new(1000.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let selected = RankSelection.select_many(&mut rng, &population, SAMPLES);
+        let best = selected.iter().filter(|i| i.fitness() == 1000.0).count();
+
+        assert_relative_eq!(best as f32 / SAMPLES as f32, 0.5, epsilon = 0.015);
+    }
+
+    #[test]
+    fn truncation() {
+        assert_frequencies(TruncationSelection::new(0.5), [0.0, 0.0, 0.5, 0.5]);
+        assert_frequencies(TruncationSelection::new(0.25), [0.0, 0.0, 0.0, 1.0]);
+        assert_frequencies(TruncationSelection::new(1.0), [0.25, 0.25, 0.25, 0.25]);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::Individual;
+use std::fmt;
+
+/// Fitness statistics of a population.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    min_fitness: f32,
+    max_fitness: f32,
+    avg_fitness: f32,
+    median_fitness: f32,
+}
+
+impl Statistics {
+    pub fn new<I>(population: &[I]) -> Self
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let mut fitnesses: Vec<_> = population.iter().map(|i| i.fitness()).collect();
+
+        fitnesses.sort_by(f32::total_cmp);
+
+        let len = fitnesses.len();
+
+        let median_fitness = if len % 2 == 0 {
+            (fitnesses[len / 2 - 1] + fitnesses[len / 2]) / 2.0
+        } else {
+            fitnesses[len / 2]
+        };
+
+        Self {
+            min_fitness: fitnesses[0],
+            max_fitness: fitnesses[len - 1],
+            avg_fitness: fitnesses.iter().sum::<f32>() / len as f32,
+            median_fitness,
+        }
+    }
+
+    pub fn min_fitness(&self) -> f32 {
+        self.min_fitness
+    }
+
+    pub fn max_fitness(&self) -> f32 {
+        self.max_fitness
+    }
+
+    pub fn avg_fitness(&self) -> f32 {
+        self.avg_fitness
+    }
+
+    pub fn median_fitness(&self) -> f32 {
+        self.median_fitness
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min={:.2}, max={:.2}, avg={:.2}, median={:.2}",
+            self.min_fitness, self.max_fitness, self.avg_fitness, self.median_fitness
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestIndividual;
+
+    fn statistics(fitnesses: &[f32]) -> Statistics {
+        let population: Vec<_> = fitnesses
+            .iter()
+            .map(|&fitness| TestIndividual::new(fitness))
+            .collect();
+
+        Statistics::new(&population)
+    }
+
+    #[test]
+    fn odd_population() {
+        let actual = statistics(&[30.0, 10.0, 20.0, 40.0, 50.0]);
+
+        assert_eq!(actual.min_fitness(), 10.0);
+        assert_eq!(actual.max_fitness(), 50.0);
+        assert_eq!(actual.avg_fitness(), 30.0);
+        assert_eq!(actual.median_fitness(), 30.0);
+    }
+
+    #[test]
+    fn even_population() {
+        let actual = statistics(&[30.0, 10.0, 20.0, 40.0]);
+
+        assert_eq!(actual.min_fitness(), 10.0);
+        assert_eq!(actual.max_fitness(), 40.0);
+        assert_eq!(actual.avg_fitness(), 25.0);
+        assert_eq!(actual.median_fitness(), 25.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            statistics(&[1.0, 2.0]).to_string(),
+            "min=1.00, max=2.00, avg=1.50, median=1.50"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lib_neural_network::{Activation, LayerTopology, Network};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn network() -> Network {
+    let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+    Network::builder()
+        .input(9)
+        .hidden(18, Activation::Relu)
+        .output(2, Activation::Tanh)
+        .random(&mut rng)
+        .unwrap()
+}
+
+fn propagate(c: &mut Criterion) {
+    let network = network();
+    let inputs: Vec<_> = (0..9).map(|i| i as f32 / 9.0).collect();
+
+    c.bench_function("propagate", |b| {
+        b.iter(|| network.propagate(black_box(inputs.clone())))
+    });
+
+    c.bench_function("propagate_into", |b| {
+        let mut scratch = network.scratch();
+        let mut out = [0.0; 2];
+
+        b.iter(|| network.propagate_into(black_box(&inputs), &mut scratch, &mut out))
+    });
+}
+
+/// Reference network storing each neuron's weights in a separate `Vec`, the
+/// way [`Network`] used to, so that it can be compared with the contiguous
+/// row-major layout.
+struct PerNeuronNetwork {
+    layers: Vec<PerNeuronLayer>,
+}
+
+struct PerNeuronLayer {
+    neurons: Vec<PerNeuron>,
+    activation: Activation,
+}
+
+struct PerNeuron {
+    bias: f32,
+    weights: Vec<f32>,
+}
+
+impl PerNeuronNetwork {
+    fn new(network: &Network) -> Self {
+        let mut weights = network.to_weights().into_iter();
+
+        let layers = network
+            .topology()
+            .iter()
+            .skip(1)
+            .map(
+                |&LayerTopology {
+                     input_neurons,
+                     output_neurons,
+                     activation,
+                 }| {
+                    let neurons = (0..output_neurons)
+                        .map(|_| PerNeuron {
+                            bias: weights.next().unwrap(),
+                            weights: weights.by_ref().take(input_neurons).collect(),
+                        })
+                        .collect();
+
+                    PerNeuronLayer {
+                        neurons,
+                        activation,
+                    }
+                },
+            )
+            .collect();
+
+        Self { layers }
+    }
+
+    fn propagate_into(&self, inputs: &[f32], scratch: &mut [Vec<f32>; 2], out: &mut [f32]) {
+        let [current, next] = scratch;
+
+        current.clear();
+        current.extend_from_slice(inputs);
+
+        for layer in &self.layers {
+            next.clear();
+
+            next.extend(layer.neurons.iter().map(|neuron| {
+                neuron.bias
+                    + current
+                        .iter()
+                        .zip(&neuron.weights)
+                        .map(|(input, weight)| input * weight)
+                        .sum::<f32>()
+            }));
+
+            layer.activation.apply(next);
+            std::mem::swap(current, next);
+        }
+
+        out.copy_from_slice(current);
+    }
+}
+
+fn layout(c: &mut Criterion) {
+    let network = network();
+    let reference = PerNeuronNetwork::new(&network);
+    let inputs: Vec<_> = (0..9).map(|i| i as f32 / 9.0).collect();
+    let mut group = c.benchmark_group("layout/9-18-2");
+
+    group.bench_function("per_neuron", |b| {
+        let mut scratch = [Vec::with_capacity(18), Vec::with_capacity(18)];
+        let mut out = [0.0; 2];
+
+        b.iter(|| reference.propagate_into(black_box(&inputs), &mut scratch, &mut out))
+    });
+
+    group.bench_function("contiguous", |b| {
+        let mut scratch = network.scratch();
+        let mut out = [0.0; 2];
+
+        b.iter(|| network.propagate_into(black_box(&inputs), &mut scratch, &mut out))
+    });
+
+    group.finish();
+}
+
+fn propagate_batch(c: &mut Criterion) {
+    let network = network();
+
+    let samples: Vec<Vec<_>> = (0..100)
+        .map(|sample| (0..9).map(|i| (sample * i) as f32 / 900.0).collect())
+        .collect();
+
+    let samples: Vec<_> = samples.iter().map(|sample| sample.as_slice()).collect();
+
+    c.bench_function("propagate_batch/100", |b| {
+        b.iter(|| network.propagate_batch(black_box(&samples)))
+    });
+}
+
+criterion_group!(benches, propagate, layout, propagate_batch);
+criterion_main!(benches);
@@ -0,0 +1,237 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum Activation {
+    #[default]
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Relu,
+    LeakyRelu {
+        alpha: f32,
+    },
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Sigmoid,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Tanh,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Identity,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Softsign,
+    /// Normalizes the whole layer into a probability distribution, so unlike
+    /// the other variants it doesn't work on neurons separately.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Softmax,
+}
+
+impl Activation {
+    pub fn apply(&self, outputs: &mut [f32]) {
+        match *self {
+            Self::Relu => outputs.iter_mut().for_each(|x| *x = x.max(0.0)),
+
+            Self::LeakyRelu { alpha } => outputs.iter_mut().for_each(|x| {
+                if *x < 0.0 {
+                    *x *= alpha;
+                }
+            }),
+
+            Self::Sigmoid => outputs
+                .iter_mut()
+                .for_each(|x| *x = 1.0 / (1.0 + (-*x).exp())),
+
+            Self::Tanh => outputs.iter_mut().for_each(|x| *x = x.tanh()),
+
+            Self::Identity => {}
+
+            Self::Softsign => outputs.iter_mut().for_each(|x| *x /= 1.0 + x.abs()),
+
+            Self::Softmax => {
+                // Subtracting the maximum doesn't change the result, but keeps
+                // `exp()` from overflowing for large inputs
+                let max = outputs.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+                outputs.iter_mut().for_each(|x| *x = (*x - max).exp());
+
+                let sum = outputs.iter().sum::<f32>();
+
+                outputs.iter_mut().for_each(|x| *x /= sum);
+            }
+        }
+    }
+
+    /// Turns gradients with respect to this activation's `outputs` into
+    /// gradients with respect to its inputs, in place.
+    pub fn backward(&self, outputs: &[f32], grads: &mut [f32]) {
+        assert_eq!(outputs.len(), grads.len());
+
+        match *self {
+            Self::Relu => grads.iter_mut().zip(outputs).for_each(|(grad, &y)| {
+                if y <= 0.0 {
+                    *grad = 0.0;
+                }
+            }),
+
+            Self::LeakyRelu { alpha } => grads.iter_mut().zip(outputs).for_each(|(grad, &y)| {
+                if y < 0.0 {
+                    *grad *= alpha;
+                }
+            }),
+
+            Self::Sigmoid => grads
+                .iter_mut()
+                .zip(outputs)
+                .for_each(|(grad, &y)| *grad *= y * (1.0 - y)),
+
+            Self::Tanh => grads
+                .iter_mut()
+                .zip(outputs)
+                .for_each(|(grad, &y)| *grad *= 1.0 - y * y),
+
+            Self::Identity => {}
+
+            // softsign(x) = x / (1 + |x|), so its derivative, 1 / (1 + |x|)²,
+            // is equal to (1 - |y|)²
+            Self::Softsign => grads.iter_mut().zip(outputs).for_each(|(grad, &y)| {
+                *grad *= (1.0 - y.abs()).powi(2);
+            }),
+
+            Self::Softmax => {
+                let dot = grads.iter().zip(outputs).map(|(g, y)| g * y).sum::<f32>();
+
+                grads
+                    .iter_mut()
+                    .zip(outputs)
+                    .for_each(|(grad, &y)| *grad = y * (*grad - dot));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn apply(activation: Activation, mut outputs: Vec<f32>) -> Vec<f32> {
+        activation.apply(&mut outputs);
+        outputs
+    }
+
+    #[test]
+    fn backward() {
+        let activations = [
+            Activation::Relu,
+            Activation::LeakyRelu { alpha: 0.1 },
+            Activation::Sigmoid,
+            Activation::Tanh,
+            Activation::Identity,
+            Activation::Softsign,
+            Activation::Softmax,
+        ];
+
+        let inputs = [-0.7, 0.3, 1.2];
+        let upstream = [0.5, -1.0, 2.0];
+
+        for activation in activations {
+            let outputs = apply(activation, inputs.to_vec());
+            let mut grads = upstream.to_vec();
+
+            activation.backward(&outputs, &mut grads);
+
+            // Compares against central differences of `sum(upstream * y)`
+            for i in 0..inputs.len() {
+                let eps = 1e-3;
+                let loss = |delta: f32| {
+                    let mut inputs = inputs.to_vec();
+                    inputs[i] += delta;
+
+                    apply(activation, inputs)
+                        .iter()
+                        .zip(&upstream)
+                        .map(|(y, g)| y * g)
+                        .sum::<f32>()
+                };
+
+                let numeric = (loss(eps) - loss(-eps)) / (2.0 * eps);
+
+                assert_relative_eq!(grads[i], numeric, epsilon = 1e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn relu() {
+        let outputs = apply(Activation::Relu, vec![-2.0, 0.0, 3.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.0, 0.0, 3.0].as_ref());
+    }
+
+    #[test]
+    fn leaky_relu() {
+        let outputs = apply(Activation::LeakyRelu { alpha: 0.1 }, vec![-2.0, 3.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [-0.2, 3.0].as_ref());
+    }
+
+    #[test]
+    fn sigmoid() {
+        let outputs = apply(Activation::Sigmoid, vec![0.0, 2.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.5, 0.880797].as_ref());
+    }
+
+    #[test]
+    fn tanh() {
+        let outputs = apply(Activation::Tanh, vec![-1.0, 0.5]);
+
+        assert_relative_eq!(outputs.as_slice(), [-0.7615942, 0.46211717].as_ref());
+    }
+
+    #[test]
+    fn identity() {
+        let outputs = apply(Activation::Identity, vec![-1.5, 2.5]);
+
+        assert_relative_eq!(outputs.as_slice(), [-1.5, 2.5].as_ref());
+    }
+
+    #[test]
+    fn softsign() {
+        let outputs = apply(Activation::Softsign, vec![-1.0, 3.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [-0.5, 0.75].as_ref());
+    }
+
+    #[test]
+    fn softmax() {
+        let outputs = apply(Activation::Softmax, vec![1.0, 2.0, 3.0]);
+
+        assert_relative_eq!(
+            outputs.as_slice(),
+            [0.09003057, 0.24472848, 0.66524094].as_ref()
+        );
+
+        // Large inputs must not overflow into NaNs:
+        let outputs = apply(Activation::Softmax, vec![1000.0, 1000.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.5, 0.5].as_ref());
+    }
+}
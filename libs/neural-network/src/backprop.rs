@@ -0,0 +1,173 @@
+use crate::{Network, NetworkError};
+
+/// Outputs of every layer, recorded by [`Network::forward()`] so that
+/// [`Network::backward()`] can compute gradients.
+#[derive(Clone, Debug)]
+pub struct Trace {
+    /// Network's inputs, followed by outputs of each layer.
+    activations: Vec<Vec<f32>>,
+}
+
+impl Trace {
+    pub fn outputs(&self) -> &[f32] {
+        self.activations.last().map_or(&[], |outputs| outputs)
+    }
+}
+
+impl Network {
+    /// Like [`Network::try_forward()`], but panics on invalid inputs.
+    pub fn forward(&self, inputs: &[f32]) -> Trace {
+        self.try_forward(inputs)
+            .unwrap_or_else(|err| panic!("couldn't propagate network: {}", err))
+    }
+
+    /// Propagates `inputs` just like [`Network::try_propagate()`], but keeps
+    /// the intermediate outputs for [`Network::backward()`].
+    pub fn try_forward(&self, inputs: &[f32]) -> Result<Trace, NetworkError> {
+        self.validate_inputs(inputs)?;
+
+        let mut activations = Vec::with_capacity(self.layers.len() + 1);
+
+        activations.push(inputs.to_vec());
+
+        for layer in &self.layers {
+            let mut outputs = vec![0.0; layer.output_size()];
+
+            layer.propagate(activations.last().unwrap(), &mut outputs);
+            activations.push(outputs);
+        }
+
+        Ok(Trace { activations })
+    }
+
+    /// Given gradient of the loss with respect to the network's outputs,
+    /// returns gradient with respect to each of its parameters, using the
+    /// same layout as [`Network::to_weights()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output_grads` doesn't match the network's outputs.
+    pub fn backward(&self, trace: &Trace, output_grads: &[f32]) -> Vec<f32> {
+        assert_eq!(output_grads.len(), trace.outputs().len());
+
+        let mut grads = vec![0.0; self.layers.iter().map(|layer| layer.weights_len()).sum()];
+        let mut offset = grads.len();
+        let mut upstream = output_grads.to_vec();
+
+        for (layer_idx, layer) in self.layers.iter().enumerate().rev() {
+            let inputs = &trace.activations[layer_idx];
+            let outputs = &trace.activations[layer_idx + 1];
+
+            // Gradient with respect to the neurons' pre-activation outputs
+            layer.activation.backward(outputs, &mut upstream);
+
+            offset -= layer.weights_len();
+
+            let layer_grads = &mut grads[offset..offset + layer.weights_len()];
+            let mut input_grads = vec![0.0; layer.input_size()];
+
+            for ((neuron, neuron_grads), &grad) in layer
+                .neurons()
+                .zip(layer_grads.chunks_exact_mut(layer.input_size() + 1))
+                .zip(&upstream)
+            {
+                neuron_grads[0] = grad;
+
+                for ((weight_grad, input), (input_grad, weight)) in neuron_grads[1..]
+                    .iter_mut()
+                    .zip(inputs)
+                    .zip(input_grads.iter_mut().zip(neuron.weights))
+                {
+                    *weight_grad = grad * input;
+                    *input_grad += grad * weight;
+                }
+            }
+
+            upstream = input_grads;
+        }
+
+        grads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Activation;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn loss(network: &Network, inputs: &[f32], targets: &[f32]) -> f32 {
+        network
+            .propagate(inputs.to_vec())
+            .iter()
+            .zip(targets)
+            .map(|(y, t)| (y - t).powi(2))
+            .sum()
+    }
+
+    #[test]
+    fn forward() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::builder()
+            .input(3)
+            .hidden(4, Activation::Tanh)
+            .output(2, Activation::Sigmoid)
+            .random(&mut rng)
+            .unwrap();
+
+        let trace = network.forward(&[0.5, -0.2, 1.0]);
+
+        assert_eq!(trace.outputs(), network.propagate(vec![0.5, -0.2, 1.0]));
+        assert_eq!(trace.activations.len(), 3);
+    }
+
+    #[test]
+    fn backward_matches_finite_differences() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut network = Network::builder()
+            .input(3)
+            .hidden(4, Activation::Tanh)
+            .hidden(3, Activation::LeakyRelu { alpha: 0.1 })
+            .output(2, Activation::Sigmoid)
+            .random(&mut rng)
+            .unwrap();
+
+        let inputs = [0.5, -0.2, 1.0];
+        let targets = [0.1, 0.9];
+
+        let trace = network.forward(&inputs);
+
+        let output_grads: Vec<_> = trace
+            .outputs()
+            .iter()
+            .zip(&targets)
+            .map(|(y, t)| 2.0 * (y - t))
+            .collect();
+
+        let grads = network.backward(&trace, &output_grads);
+        let weights = network.to_weights();
+
+        assert_eq!(grads.len(), weights.len());
+
+        for i in 0..weights.len() {
+            let eps = 1e-2;
+            let mut perturbed = weights.clone();
+
+            perturbed[i] = weights[i] + eps;
+            network.set_weights(&perturbed).unwrap();
+            let loss_plus = loss(&network, &inputs, &targets);
+
+            perturbed[i] = weights[i] - eps;
+            network.set_weights(&perturbed).unwrap();
+            let loss_minus = loss(&network, &inputs, &targets);
+
+            let numeric = (loss_plus - loss_minus) / (2.0 * eps);
+
+            assert_relative_eq!(grads[i], numeric, epsilon = 1e-3);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Activation, Initializer, LayerTopology, Network, NetworkError};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Assembles a topology layer by layer, e.g.:
+///
+/// ```
+/// # use lib_neural_network::{Activation, Network};
+/// # use rand::SeedableRng;
+/// # let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+/// let network = Network::builder()
+///     .input(9)
+///     .hidden(18, Activation::Relu)
+///     .output(2, Activation::Tanh)
+///     .random(&mut rng)
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NetworkBuilder {
+    layers: Vec<LayerTopology>,
+    initializer: Initializer,
+}
+
+impl NetworkBuilder {
+    pub fn input(mut self, neurons: usize) -> Self {
+        self.layers.push(LayerTopology {
+            input_neurons: neurons,
+            output_neurons: neurons,
+            activation: Activation::Identity,
+        });
+
+        self
+    }
+
+    pub fn hidden(self, neurons: usize, activation: Activation) -> Self {
+        self.layer(neurons, activation)
+    }
+
+    pub fn output(self, neurons: usize, activation: Activation) -> Self {
+        self.layer(neurons, activation)
+    }
+
+    pub fn initializer(mut self, initializer: Initializer) -> Self {
+        self.initializer = initializer;
+        self
+    }
+
+    pub fn topology(&self) -> &[LayerTopology] {
+        &self.layers
+    }
+
+    pub fn random(&self, rng: &mut dyn RngCore) -> Result<Network, NetworkError> {
+        Network::try_random_with(rng, &self.layers, &self.initializer)
+    }
+
+    pub fn random_seeded(&self, seed: u64) -> Result<Network, NetworkError> {
+        self.random(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    pub fn from_weights(&self, weights: &[f32]) -> Result<Network, NetworkError> {
+        Network::from_weights(&self.layers, weights)
+    }
+
+    fn layer(mut self, neurons: usize, activation: Activation) -> Self {
+        let input_neurons = self.layers.last().map_or(0, |layer| layer.output_neurons);
+
+        self.layers.push(LayerTopology {
+            input_neurons,
+            output_neurons: neurons,
+            activation,
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topology() {
+        let builder = Network::builder()
+            .input(9)
+            .hidden(18, Activation::Relu)
+            .output(2, Activation::Tanh);
+
+        assert_eq!(
+            builder.topology(),
+            [
+                LayerTopology {
+                    input_neurons: 9,
+                    output_neurons: 9,
+                    activation: Activation::Identity,
+                },
+                LayerTopology {
+                    input_neurons: 9,
+                    output_neurons: 18,
+                    activation: Activation::Relu,
+                },
+                LayerTopology {
+                    input_neurons: 18,
+                    output_neurons: 2,
+                    activation: Activation::Tanh,
+                },
+            ]
+        );
+
+        assert_eq!(Network::weights_len(builder.topology()), 10 * 18 + 19 * 2);
+    }
+
+    #[test]
+    fn missing_input() {
+        assert_eq!(
+            Network::builder()
+                .output(2, Activation::Tanh)
+                .from_weights(&[])
+                .unwrap_err(),
+            NetworkError::TopologyTooShort { layers: 1 }
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Compact binary checkpoints.
+//!
+//! All numbers are little-endian:
+//!
+//! - magic bytes, `SLNN`,
+//! - format version (`u16`, currently `1`),
+//! - number of topology entries (`u32`),
+//! - for each topology entry: `input_neurons` (`u32`), `output_neurons`
+//!   (`u32`), activation tag (`u8`) and activation parameter (`f32`),
+//! - weights (`f32` each), in the order of [`Network::to_weights()`],
+//! - CRC-32 (IEEE) of all the preceding bytes (`u32`).
+//!
+//! Activation tags are: 0 - ReLU, 1 - leaky ReLU (parameter is its alpha),
+//! 2 - sigmoid, 3 - tanh, 4 - identity, 5 - softsign, 6 - softmax; parameter
+//! is zero for activations that don't have one.
+
+use crate::{Activation, LayerTopology, Network, NetworkError};
+use std::io::{self, Read, Write};
+use std::{error, fmt};
+
+const MAGIC: [u8; 4] = *b"SLNN";
+const VERSION: u16 = 1;
+
+#[derive(Debug)]
+pub enum CheckpointError {
+    Io(io::Error),
+    Truncated,
+    InvalidMagic,
+    UnsupportedVersion { version: u16 },
+    InvalidActivation { tag: u8 },
+    TooLarge,
+    ChecksumMismatch { expected: u32, actual: u32 },
+    Network(NetworkError),
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "couldn't access checkpoint: {}", err),
+            Self::Truncated => write!(f, "checkpoint is truncated"),
+            Self::InvalidMagic => write!(f, "not a network checkpoint"),
+            Self::UnsupportedVersion { version } => write!(
+                f,
+                "unsupported checkpoint version: {} (expected {})",
+                version, VERSION
+            ),
+            Self::InvalidActivation { tag } => write!(f, "unknown activation tag: {}", tag),
+            Self::TooLarge => write!(f, "checkpoint describes a network that is too large"),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checkpoint is corrupted: checksum is {:#010x}, but data hashes to {:#010x}",
+                expected, actual
+            ),
+            Self::Network(err) => write!(f, "checkpoint contains an invalid network: {}", err),
+        }
+    }
+}
+
+impl error::Error for CheckpointError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Network(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CheckpointError {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::UnexpectedEof {
+            Self::Truncated
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+impl From<NetworkError> for CheckpointError {
+    fn from(err: NetworkError) -> Self {
+        Self::Network(err)
+    }
+}
+
+impl Network {
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        let topology = self.topology();
+        let mut buf = Vec::new();
+
+        buf.extend_from_slice(&MAGIC);
+        buf.extend_from_slice(&VERSION.to_le_bytes());
+        buf.extend_from_slice(&(topology.len() as u32).to_le_bytes());
+
+        for layer in &topology {
+            let (tag, param) = encode_activation(layer.activation);
+
+            buf.extend_from_slice(&(layer.input_neurons as u32).to_le_bytes());
+            buf.extend_from_slice(&(layer.output_neurons as u32).to_le_bytes());
+            buf.push(tag);
+            buf.extend_from_slice(&param.to_le_bytes());
+        }
+
+        for weight in self.weights() {
+            buf.extend_from_slice(&weight.to_le_bytes());
+        }
+
+        buf.extend_from_slice(&crc32(0, &buf).to_le_bytes());
+
+        writer.write_all(&buf)
+    }
+
+    pub fn read_from(reader: impl Read) -> Result<Self, CheckpointError> {
+        let mut reader = ChecksumReader { reader, crc: 0 };
+
+        if reader.read_array()? != MAGIC {
+            return Err(CheckpointError::InvalidMagic);
+        }
+
+        let version = u16::from_le_bytes(reader.read_array()?);
+
+        if version != VERSION {
+            return Err(CheckpointError::UnsupportedVersion { version });
+        }
+
+        let layers = reader.read_u32()?;
+
+        let topology = (0..layers)
+            .map(|_| {
+                let input_neurons = reader.read_u32()? as usize;
+                let output_neurons = reader.read_u32()? as usize;
+                let [tag] = reader.read_array()?;
+                let param = reader.read_f32()?;
+
+                Ok(LayerTopology {
+                    input_neurons,
+                    output_neurons,
+                    activation: decode_activation(tag, param)?,
+                })
+            })
+            .collect::<Result<Vec<_>, CheckpointError>>()?;
+
+        LayerTopology::validate(&topology)?;
+
+        // Sizes come straight from the file, so a corrupted entry could make
+        // `Network::weights_len()` overflow
+        let weights_len = topology
+            .windows(2)
+            .try_fold(0usize, |len, layers| {
+                (layers[1].input_neurons + 1)
+                    .checked_mul(layers[1].output_neurons)
+                    .and_then(|layer_len| len.checked_add(layer_len))
+            })
+            .ok_or(CheckpointError::TooLarge)?;
+
+        let weights = (0..weights_len)
+            .map(|_| reader.read_f32())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let actual = reader.crc;
+        let expected = u32::from_le_bytes(reader.read_array()?);
+
+        if expected != actual {
+            return Err(CheckpointError::ChecksumMismatch { expected, actual });
+        }
+
+        Ok(Self::from_weights(&topology, &weights)?)
+    }
+}
+
+struct ChecksumReader<R> {
+    reader: R,
+    crc: u32,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], CheckpointError> {
+        let mut buf = [0; N];
+
+        self.reader.read_exact(&mut buf)?;
+        self.crc = crc32(self.crc, &buf);
+
+        Ok(buf)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, CheckpointError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_f32(&mut self) -> Result<f32, CheckpointError> {
+        Ok(f32::from_le_bytes(self.read_array()?))
+    }
+}
+
+fn encode_activation(activation: Activation) -> (u8, f32) {
+    match activation {
+        Activation::Relu => (0, 0.0),
+        Activation::LeakyRelu { alpha } => (1, alpha),
+        Activation::Sigmoid => (2, 0.0),
+        Activation::Tanh => (3, 0.0),
+        Activation::Identity => (4, 0.0),
+        Activation::Softsign => (5, 0.0),
+        Activation::Softmax => (6, 0.0),
+    }
+}
+
+fn decode_activation(tag: u8, param: f32) -> Result<Activation, CheckpointError> {
+    Ok(match tag {
+        0 => Activation::Relu,
+        1 => Activation::LeakyRelu { alpha: param },
+        2 => Activation::Sigmoid,
+        3 => Activation::Tanh,
+        4 => Activation::Identity,
+        5 => Activation::Softsign,
+        6 => Activation::Softmax,
+        tag => return Err(CheckpointError::InvalidActivation { tag }),
+    })
+}
+
+/// Continues CRC-32 (IEEE 802.3) computation over given bytes; start with
+/// `crc = 0`.
+fn crc32(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB88320 & (crc & 1).wrapping_neg());
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn network() -> Network {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Network::builder()
+            .input(3)
+            .hidden(4, Activation::LeakyRelu { alpha: 0.01 })
+            .output(2, Activation::Tanh)
+            .random(&mut rng)
+            .unwrap()
+    }
+
+    fn checkpoint() -> Vec<u8> {
+        let mut buf = Vec::new();
+        network().write_to(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(0, b"123456789"), 0xCBF43926);
+        assert_eq!(crc32(crc32(0, b"1234"), b"56789"), 0xCBF43926);
+    }
+
+    #[test]
+    fn layout() {
+        let buf = checkpoint();
+
+        assert_eq!(&buf[0..4], b"SLNN");
+        assert_eq!(&buf[4..6], [1, 0]);
+        assert_eq!(&buf[6..10], [3, 0, 0, 0]);
+        assert_eq!(
+            buf.len(),
+            10 + 3 * 13 + 4 * Network::weights_len(&network().topology()) + 4
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let network = network();
+        let restored = Network::read_from(checkpoint().as_slice()).unwrap();
+
+        assert_eq!(restored.topology(), network.topology());
+        assert_eq!(restored.to_weights(), network.to_weights());
+    }
+
+    #[test]
+    fn rejects_truncated() {
+        let buf = checkpoint();
+
+        for len in 0..buf.len() {
+            assert!(
+                matches!(
+                    Network::read_from(&buf[..len]),
+                    Err(CheckpointError::Truncated)
+                ),
+                "len = {}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_corrupted() {
+        let mut buf = checkpoint();
+        let idx = buf.len() - 10;
+
+        buf[idx] ^= 0x01;
+
+        assert!(matches!(
+            Network::read_from(buf.as_slice()),
+            Err(CheckpointError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_magic() {
+        let mut buf = checkpoint();
+
+        buf[0] = b'X';
+
+        assert!(matches!(
+            Network::read_from(buf.as_slice()),
+            Err(CheckpointError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let mut buf = checkpoint();
+
+        buf[4] = 2;
+
+        assert!(matches!(
+            Network::read_from(buf.as_slice()),
+            Err(CheckpointError::UnsupportedVersion { version: 2 })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_activation() {
+        let mut buf = checkpoint();
+
+        buf[10 + 8] = 42;
+
+        assert!(matches!(
+            Network::read_from(buf.as_slice()),
+            Err(CheckpointError::InvalidActivation { tag: 42 })
+        ));
+    }
+}
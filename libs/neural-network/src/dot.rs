@@ -0,0 +1,71 @@
+//! Dot product used by neurons; with the `simd` feature it's vectorized
+//! through `wide`, which picks the best instruction set available for the
+//! target and falls back to scalar code otherwise.
+
+pub(crate) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    #[cfg(feature = "simd")]
+    return simd(a, b);
+
+    #[cfg(not(feature = "simd"))]
+    return scalar(a, b);
+}
+
+pub(crate) fn scalar(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(feature = "simd")]
+pub(crate) fn simd(a: &[f32], b: &[f32]) -> f32 {
+    use wide::f32x8;
+
+    let len = a.len().min(b.len());
+    let (a, b) = (&a[..len], &b[..len]);
+
+    let a_chunks = a.chunks_exact(8);
+    let b_chunks = b.chunks_exact(8);
+    let remainder = scalar(a_chunks.remainder(), b_chunks.remainder());
+
+    let sum = a_chunks.zip(b_chunks).fold(f32x8::ZERO, |sum, (a, b)| {
+        let a = f32x8::from(<[f32; 8]>::try_from(a).unwrap());
+        let b = f32x8::from(<[f32; 8]>::try_from(b).unwrap());
+
+        a.mul_add(b, sum)
+    });
+
+    sum.reduce_add() + remainder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_dot() {
+        assert_relative_eq!(scalar(&[1.0, 2.0, 3.0], &[4.0, -5.0, 6.0]), 12.0);
+        assert_relative_eq!(scalar(&[], &[]), 0.0);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_matches_scalar() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha8Rng;
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        // Covers empty inputs, inputs shorter than a single lane, exact
+        // multiples of lanes and inputs with remainders
+        for len in [0, 1, 7, 8, 9, 16, 18, 31, 100] {
+            let a: Vec<f32> = (0..len).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let b: Vec<f32> = (0..len).map(|_| rng.gen_range(-1.0..1.0)).collect();
+
+            assert_relative_eq!(
+                simd(&a, &b),
+                scalar(&a, &b),
+                epsilon = 1e-5,
+                max_relative = 1e-5
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{error, fmt};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NetworkError {
+    TopologyTooShort {
+        layers: usize,
+    },
+    EmptyLayer {
+        layer: usize,
+    },
+    TopologyMismatch {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    WeightsMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    InputSizeMismatch {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    OutputSizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    NonFiniteInput {
+        index: usize,
+        value: f32,
+    },
+    InvalidInitializer {
+        parameter: &'static str,
+        value: f32,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TopologyTooShort { layers } => write!(
+                f,
+                "topology must contain at least two layers (input and output), but got {}",
+                layers
+            ),
+            Self::EmptyLayer { layer } => write!(f, "layer #{} has no neurons", layer),
+            Self::TopologyMismatch {
+                layer,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "layer #{} should have {} input neurons, but has {}",
+                layer, expected, actual
+            ),
+            Self::WeightsMismatch { expected, actual } => write!(
+                f,
+                "topology requires {} weights, but got {}",
+                expected, actual
+            ),
+            Self::InputSizeMismatch {
+                layer,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "layer #{} expects {} inputs, but got {}",
+                layer, expected, actual
+            ),
+            Self::OutputSizeMismatch { expected, actual } => write!(
+                f,
+                "network returns {} outputs, but the output buffer holds {}",
+                expected, actual
+            ),
+            Self::NonFiniteInput { index, value } => {
+                write!(f, "input #{} is not a finite number: {}", index, value)
+            }
+            Self::InvalidInitializer {
+                parameter,
+                value,
+                reason,
+            } => write!(
+                f,
+                "initializer's `{}` {}, but got {}",
+                parameter, reason, value
+            ),
+        }
+    }
+}
+
+impl error::Error for NetworkError {}
@@ -0,0 +1,167 @@
+use crate::Network;
+
+const EPSILON: f32 = 1e-2;
+
+/// Result of [`gradcheck()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradCheckReport {
+    /// Worst relative error between analytic and numerical gradients, for
+    /// each layer.
+    pub layers: Vec<f32>,
+}
+
+impl GradCheckReport {
+    /// Returns the worst relative error across all layers.
+    pub fn max_error(&self) -> f32 {
+        self.layers.iter().copied().fold(0.0, f32::max)
+    }
+}
+
+/// Compares gradients returned by [`Network::backward()`] with ones obtained
+/// through central finite differences, perturbing each weight and bias by
+/// `1e-2`.
+///
+/// `loss` receives the network's outputs and must return the loss together
+/// with its gradient with respect to those outputs - e.g.
+/// `|outputs| Loss::Mse.compute(outputs, &targets)`.
+///
+/// Note that finite differences are unreliable around kinks (e.g. ReLU at
+/// zero) and that, since everything happens on `f32`s, errors of about `1e-3`
+/// are expected.
+pub fn gradcheck(
+    network: &Network,
+    inputs: &[f32],
+    loss: impl Fn(&[f32]) -> (f32, Vec<f32>),
+) -> GradCheckReport {
+    let trace = network.forward(inputs);
+    let (_, output_grads) = loss(trace.outputs());
+    let grads = network.backward(&trace, &output_grads);
+
+    let weights = network.to_weights();
+    let mut network = network.clone();
+    let mut perturbed = weights.clone();
+
+    let eval = |network: &mut Network, perturbed: &[f32]| {
+        network
+            .set_weights(perturbed)
+            .expect("weights' layout doesn't change");
+
+        loss(&network.propagate(inputs.to_vec())).0
+    };
+
+    let layer_lens: Vec<_> = network
+        .layers
+        .iter()
+        .map(|layer| layer.weights_len())
+        .collect();
+    let mut layers = Vec::with_capacity(layer_lens.len());
+    let mut offset = 0;
+
+    for len in layer_lens {
+        let error = (offset..offset + len)
+            .map(|idx| {
+                perturbed[idx] = weights[idx] + EPSILON;
+                let loss_plus = eval(&mut network, &perturbed);
+
+                perturbed[idx] = weights[idx] - EPSILON;
+                let loss_minus = eval(&mut network, &perturbed);
+
+                perturbed[idx] = weights[idx];
+
+                let numeric = (loss_plus - loss_minus) / (2.0 * EPSILON);
+
+                relative_error(grads[idx], numeric)
+            })
+            .fold(0.0, f32::max);
+
+        layers.push(error);
+        offset += len;
+    }
+
+    GradCheckReport { layers }
+}
+
+/// Relative error that doesn't explode when both gradients are close to zero.
+fn relative_error(analytic: f32, numeric: f32) -> f32 {
+    (analytic - numeric).abs() / (analytic.abs() + numeric.abs()).max(1e-3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Activation, Loss};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn network(hidden: Activation, output: Activation) -> Network {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Network::builder()
+            .input(3)
+            .hidden(5, hidden)
+            .hidden(4, Activation::Tanh)
+            .output(3, output)
+            .random(&mut rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn correct_gradients() {
+        let inputs = [0.5, -0.2, 1.0];
+        let targets = [0.2, 0.3, 0.5];
+
+        let cases = [
+            (Activation::Tanh, Activation::Identity, Loss::Mse),
+            (
+                Activation::Sigmoid,
+                Activation::Sigmoid,
+                Loss::BinaryCrossEntropy,
+            ),
+            (Activation::Softsign, Activation::Softmax, Loss::Mse),
+            (
+                Activation::LeakyRelu { alpha: 0.1 },
+                Activation::Identity,
+                Loss::SoftmaxCrossEntropy,
+            ),
+            (
+                Activation::Tanh,
+                Activation::Tanh,
+                Loss::Huber { delta: 0.5 },
+            ),
+        ];
+
+        for (hidden, output, loss) in cases {
+            let report = gradcheck(&network(hidden, output), &inputs, |outputs| {
+                loss.compute(outputs, &targets)
+            });
+
+            assert_eq!(report.layers.len(), 3);
+            assert!(
+                report.max_error() < 1e-2,
+                "{:?} / {:?} / {:?}: {:?}",
+                hidden,
+                output,
+                loss,
+                report
+            );
+        }
+    }
+
+    #[test]
+    fn detects_wrong_gradients() {
+        let network = network(Activation::Tanh, Activation::Identity);
+        let targets = [0.2, 0.3, 0.5];
+
+        // Gradient is off by a factor of two
+        let report = gradcheck(&network, &[0.5, -0.2, 1.0], |outputs| {
+            let (loss, grads) = Loss::Mse.compute(outputs, &targets);
+            (loss, grads.iter().map(|grad| grad * 2.0).collect())
+        });
+
+        assert!(
+            report.layers.iter().all(|&error| error > 0.3),
+            "{:?}",
+            report
+        );
+    }
+}
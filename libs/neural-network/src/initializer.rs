@@ -0,0 +1,433 @@
+use crate::NetworkError;
+use rand::{Rng, RngCore};
+use std::f32::consts::PI;
+
+/// Describes how [`crate::Network::random_with()`] draws the initial
+/// parameters.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Initializer {
+    pub weights: WeightInit,
+    pub biases: BiasInit,
+}
+
+/// `fan_in` and `fan_out` below refer to the number of layer's inputs and
+/// neurons, respectively.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum WeightInit {
+    /// Uniform on `(-limit, limit)`.
+    Uniform { limit: f32 },
+    /// Xavier / Glorot: uniform with variance `2 / (fan_in + fan_out)`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    XavierUniform,
+    /// Xavier / Glorot: normal with variance `2 / (fan_in + fan_out)`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    XavierNormal,
+    /// He / Kaiming: uniform with variance `2 / fan_in`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    HeUniform,
+    /// He / Kaiming: normal with variance `2 / fan_in`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    HeNormal,
+    /// LeCun: uniform with variance `1 / fan_in`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "lecun_uniform",
+            deserialize_with = "crate::schema::unit_variant"
+        )
+    )]
+    LeCunUniform,
+    /// LeCun: normal with variance `1 / fan_in`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "lecun_normal",
+            deserialize_with = "crate::schema::unit_variant"
+        )
+    )]
+    LeCunNormal,
+    /// (Semi-)orthogonal matrix scaled by `gain`.
+    Orthogonal { gain: f32 },
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Zeros,
+}
+
+impl Default for WeightInit {
+    fn default() -> Self {
+        Self::Uniform { limit: 1.0 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum BiasInit {
+    /// Uniform on `(-limit, limit)`.
+    Uniform {
+        limit: f32,
+    },
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::schema::unit_variant")
+    )]
+    Zeros,
+    Constant {
+        value: f32,
+    },
+}
+
+impl Default for BiasInit {
+    fn default() -> Self {
+        Self::Uniform { limit: 1.0 }
+    }
+}
+
+impl Initializer {
+    /// Checks that uniform limits are positive and that the remaining
+    /// parameters are finite - otherwise drawing from them would panic or
+    /// yield NaNs.
+    pub fn validate(&self) -> Result<(), NetworkError> {
+        let invalid = |parameter, value, reason| NetworkError::InvalidInitializer {
+            parameter,
+            value,
+            reason,
+        };
+
+        match self.weights {
+            WeightInit::Uniform { limit } if !is_positive(limit) => {
+                return Err(invalid("weights.limit", limit, "must be positive"));
+            }
+
+            WeightInit::Orthogonal { gain } if !gain.is_finite() => {
+                return Err(invalid("weights.gain", gain, "must be finite"));
+            }
+
+            _ => (),
+        }
+
+        match self.biases {
+            BiasInit::Uniform { limit } if !is_positive(limit) => {
+                Err(invalid("biases.limit", limit, "must be positive"))
+            }
+
+            BiasInit::Constant { value } if !value.is_finite() => {
+                Err(invalid("biases.value", value, "must be finite"))
+            }
+
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns layer's weights (row-major, one row per neuron) and biases.
+    pub(crate) fn init(
+        &self,
+        rng: &mut dyn RngCore,
+        fan_in: usize,
+        fan_out: usize,
+    ) -> (Vec<f32>, Vec<f32>) {
+        let mut weights = Vec::with_capacity(fan_in * fan_out);
+        let mut biases = Vec::with_capacity(fan_out);
+
+        if let WeightInit::Orthogonal { gain } = self.weights {
+            weights = orthogonal(rng, fan_in, fan_out, gain);
+            biases.extend((0..fan_out).map(|_| self.biases.sample(rng)));
+        } else {
+            // Draws neuron by neuron, weights first - this order is a part of
+            // the reproducibility guarantee of `Network::random()`
+            for _ in 0..fan_out {
+                weights.extend((0..fan_in).map(|_| self.weights.sample(rng, fan_in, fan_out)));
+                biases.push(self.biases.sample(rng));
+            }
+        }
+
+        (weights, biases)
+    }
+}
+
+impl WeightInit {
+    fn sample(&self, rng: &mut dyn RngCore, fan_in: usize, fan_out: usize) -> f32 {
+        let (fan_in, fan_out) = (fan_in as f32, fan_out as f32);
+
+        match *self {
+            Self::Uniform { limit } => uniform(rng, limit),
+            Self::XavierUniform => uniform(rng, (6.0 / (fan_in + fan_out)).sqrt()),
+            Self::XavierNormal => normal(rng) * (2.0 / (fan_in + fan_out)).sqrt(),
+            Self::HeUniform => uniform(rng, (6.0 / fan_in).sqrt()),
+            Self::HeNormal => normal(rng) * (2.0 / fan_in).sqrt(),
+            Self::LeCunUniform => uniform(rng, (3.0 / fan_in).sqrt()),
+            Self::LeCunNormal => normal(rng) * (1.0 / fan_in).sqrt(),
+            Self::Orthogonal { .. } => unreachable!("orthogonal weights are drawn per layer"),
+            Self::Zeros => 0.0,
+        }
+    }
+}
+
+impl BiasInit {
+    fn sample(&self, rng: &mut dyn RngCore) -> f32 {
+        match *self {
+            Self::Uniform { limit } => uniform(rng, limit),
+            Self::Zeros => 0.0,
+            Self::Constant { value } => value,
+        }
+    }
+}
+
+fn is_positive(value: f32) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+fn uniform(rng: &mut dyn RngCore, limit: f32) -> f32 {
+    rng.gen_range(-limit..limit)
+}
+
+/// Draws from the standard normal distribution using the Box-Muller
+/// transform; implemented here (instead of using `rand_distr`) so that the
+/// generated values don't depend on other crates' versions.
+fn normal(rng: &mut dyn RngCore) -> f32 {
+    let u1 = 1.0 - rng.gen::<f32>();
+    let u2 = rng.gen::<f32>();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// Returns a `fan_out × fan_in` matrix with orthonormal rows (or columns, if
+/// there are more rows than columns), obtained by running Gram-Schmidt on a
+/// random normal matrix.
+fn orthogonal(rng: &mut dyn RngCore, fan_in: usize, fan_out: usize, gain: f32) -> Vec<f32> {
+    // Vectors to orthonormalize: rows, or columns of the transposed matrix
+    let (count, len) = if fan_out <= fan_in {
+        (fan_out, fan_in)
+    } else {
+        (fan_in, fan_out)
+    };
+
+    let mut vectors: Vec<Vec<f32>> = (0..count)
+        .map(|_| (0..len).map(|_| normal(rng)).collect())
+        .collect();
+
+    for i in 0..count {
+        for j in 0..i {
+            let dot = dot(&vectors[i], &vectors[j]);
+            let (head, tail) = vectors.split_at_mut(i);
+
+            for (x, y) in tail[0].iter_mut().zip(&head[j]) {
+                *x -= dot * y;
+            }
+        }
+
+        let norm = dot(&vectors[i], &vectors[i]).sqrt();
+
+        vectors[i].iter_mut().for_each(|x| *x /= norm);
+    }
+
+    let mut weights = vec![0.0; fan_in * fan_out];
+
+    for row in 0..fan_out {
+        for col in 0..fan_in {
+            weights[row * fan_in + col] = gain
+                * if fan_out <= fan_in {
+                    vectors[row][col]
+                } else {
+                    vectors[col][row]
+                };
+        }
+    }
+
+    weights
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    const FAN_IN: usize = 200;
+    const FAN_OUT: usize = 300;
+
+    fn weights(init: WeightInit) -> Vec<f32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Initializer {
+            weights: init,
+            biases: BiasInit::Zeros,
+        }
+        .init(&mut rng, FAN_IN, FAN_OUT)
+        .0
+    }
+
+    fn variance(values: &[f32]) -> f32 {
+        let n = values.len() as f32;
+        let mean = values.iter().sum::<f32>() / n;
+
+        values.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / (n - 1.0)
+    }
+
+    fn assert_variance(init: WeightInit, expected: f32) {
+        let weights = weights(init);
+
+        assert_eq!(weights.len(), FAN_IN * FAN_OUT);
+        assert_relative_eq!(variance(&weights), expected, max_relative = 0.03);
+    }
+
+    #[test]
+    fn uniform() {
+        assert_variance(WeightInit::Uniform { limit: 1.0 }, 1.0 / 3.0);
+    }
+
+    #[test]
+    fn xavier() {
+        let expected = 2.0 / (FAN_IN + FAN_OUT) as f32;
+
+        assert_variance(WeightInit::XavierUniform, expected);
+        assert_variance(WeightInit::XavierNormal, expected);
+    }
+
+    #[test]
+    fn he() {
+        let expected = 2.0 / FAN_IN as f32;
+
+        assert_variance(WeightInit::HeUniform, expected);
+        assert_variance(WeightInit::HeNormal, expected);
+    }
+
+    #[test]
+    fn lecun() {
+        let expected = 1.0 / FAN_IN as f32;
+
+        assert_variance(WeightInit::LeCunUniform, expected);
+        assert_variance(WeightInit::LeCunNormal, expected);
+    }
+
+    #[test]
+    fn zeros() {
+        assert!(weights(WeightInit::Zeros).iter().all(|&w| w == 0.0));
+    }
+
+    #[test]
+    fn normal_is_standard() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let values: Vec<_> = (0..50_000).map(|_| normal(&mut rng)).collect();
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+
+        assert_relative_eq!(mean, 0.0, epsilon = 0.02);
+        assert_relative_eq!(variance(&values), 1.0, max_relative = 0.03);
+    }
+
+    #[test]
+    fn orthogonal() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        // (fan_in, fan_out) - both wide and tall matrices
+        for (fan_in, fan_out) in [(6, 4), (4, 6), (5, 5)] {
+            let weights = super::orthogonal(&mut rng, fan_in, fan_out, 2.0);
+            let at = |row: usize, col: usize| weights[row * fan_in + col];
+
+            let (count, len) = if fan_out <= fan_in {
+                (fan_out, fan_in)
+            } else {
+                (fan_in, fan_out)
+            };
+
+            let vector = |i: usize| -> Vec<f32> {
+                (0..len)
+                    .map(|j| {
+                        if fan_out <= fan_in {
+                            at(i, j)
+                        } else {
+                            at(j, i)
+                        }
+                    })
+                    .collect()
+            };
+
+            for i in 0..count {
+                for j in 0..count {
+                    let expected = if i == j { 4.0 } else { 0.0 };
+
+                    assert_relative_eq!(dot(&vector(i), &vector(j)), expected, epsilon = 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn biases() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let (_, biases) = Initializer {
+            weights: WeightInit::HeNormal,
+            biases: BiasInit::Constant { value: 0.1 },
+        }
+        .init(&mut rng, 3, 4);
+
+        assert_eq!(biases, [0.1; 4]);
+
+        let (_, biases) = Initializer {
+            weights: WeightInit::Orthogonal { gain: 1.0 },
+            biases: BiasInit::Zeros,
+        }
+        .init(&mut rng, 3, 4);
+
+        assert_eq!(biases, [0.0; 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let initializer = Initializer {
+            weights: WeightInit::LeCunNormal,
+            biases: BiasInit::Constant { value: 0.5 },
+        };
+
+        let json = serde_json::to_string(&initializer).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"weights":{"type":"lecun_normal"},"biases":{"type":"constant","value":0.5}}"#
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Initializer>(&json).unwrap(),
+            initializer
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Initializer>("{}").unwrap(),
+            Initializer::default()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{neuron::Neuron, Activation, Initializer};
+use rand::RngCore;
+
+/// Fully-connected layer; `weights` are stored row-major, one row of
+/// `input_size` weights per neuron.
+#[derive(Clone, Debug)]
+pub(crate) struct Layer {
+    pub(crate) input_size: usize,
+    pub(crate) weights: Vec<f32>,
+    pub(crate) biases: Vec<f32>,
+    pub(crate) activation: Activation,
+}
+
+impl Layer {
+    pub(crate) fn random(
+        rng: &mut dyn RngCore,
+        input_size: usize,
+        output_size: usize,
+        activation: Activation,
+        initializer: &Initializer,
+    ) -> Self {
+        let (weights, biases) = initializer.init(rng, input_size, output_size);
+
+        Self {
+            input_size,
+            weights,
+            biases,
+            activation,
+        }
+    }
+
+    pub(crate) fn from_weights(
+        input_size: usize,
+        output_size: usize,
+        activation: Activation,
+        weights: &mut dyn Iterator<Item = f32>,
+    ) -> Option<Self> {
+        let mut layer_weights = Vec::with_capacity(input_size * output_size);
+        let mut biases = Vec::with_capacity(output_size);
+
+        for _ in 0..output_size {
+            biases.push(weights.next()?);
+
+            for _ in 0..input_size {
+                layer_weights.push(weights.next()?);
+            }
+        }
+
+        Some(Self {
+            input_size,
+            weights: layer_weights,
+            biases,
+            activation,
+        })
+    }
+
+    pub(crate) fn input_size(&self) -> usize {
+        self.input_size
+    }
+
+    pub(crate) fn output_size(&self) -> usize {
+        self.biases.len()
+    }
+
+    /// Returns how many parameters (weights and biases) this layer has.
+    pub(crate) fn weights_len(&self) -> usize {
+        self.weights.len() + self.biases.len()
+    }
+
+    /// Overwrites parameters, using the same layout as [`Layer::from_weights()`].
+    pub(crate) fn set_weights(&mut self, weights: &[f32]) {
+        assert_eq!(weights.len(), self.weights_len());
+
+        let rows = self.weights.chunks_exact_mut(self.input_size);
+        let neurons = weights.chunks_exact(self.input_size + 1);
+
+        for ((bias, row), neuron) in self.biases.iter_mut().zip(rows).zip(neurons) {
+            *bias = neuron[0];
+            row.copy_from_slice(&neuron[1..]);
+        }
+    }
+
+    pub(crate) fn neurons(&self) -> impl Iterator<Item = Neuron<'_>> {
+        self.weights
+            .chunks_exact(self.input_size)
+            .zip(&self.biases)
+            .map(|(weights, &bias)| Neuron { weights, bias })
+    }
+
+    pub(crate) fn propagate(&self, inputs: &[f32], outputs: &mut [f32]) {
+        assert_eq!(outputs.len(), self.output_size());
+
+        for (output, neuron) in outputs.iter_mut().zip(self.neurons()) {
+            *output = neuron.propagate(inputs);
+        }
+
+        self.activation.apply(outputs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn propagated(layer: &Layer, inputs: &[f32]) -> Vec<f32> {
+        let mut outputs = vec![0.0; layer.output_size()];
+        layer.propagate(inputs, &mut outputs);
+        outputs
+    }
+
+    #[test]
+    fn random() {
+        // Because we always use the same seed, our `rng` in here will
+        // always return the same set of values
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let layer = Layer::random(&mut rng, 4, 1, Activation::Relu, &Initializer::default());
+
+        assert_eq!(layer.biases, [0.5238805]);
+        assert_eq!(
+            layer.weights,
+            [-0.6255188, 0.67383933, 0.81812596, 0.26284885]
+        );
+    }
+
+    #[test]
+    fn propagate() {
+        let layer = Layer {
+            input_size: 2,
+            weights: vec![-0.3, 0.8],
+            biases: vec![0.5],
+            activation: Activation::default(),
+        };
+
+        // Ensures ReLU, our default activation, works:
+        let outputs = propagated(&layer, &[-10.0, -10.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.0].as_ref());
+
+        // `0.5` and `1.0` chosen by a fair dice roll:
+        let outputs = propagated(&layer, &[0.5, 1.0]);
+
+        assert_relative_eq!(
+            outputs.as_slice(),
+            [(-0.3 * 0.5) + (0.8 * 1.0) + 0.5].as_ref(),
+        );
+    }
+
+    #[test]
+    fn propagate_with_softmax() {
+        let layer = Layer {
+            input_size: 1,
+            weights: vec![1.0, -1.0],
+            biases: vec![0.0, 0.0],
+            activation: Activation::Softmax,
+        };
+
+        let outputs = propagated(&layer, &[0.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.5, 0.5].as_ref());
+    }
+
+    #[test]
+    fn neurons() {
+        let layer = Layer {
+            input_size: 2,
+            weights: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            biases: vec![0.1, 0.2, 0.3],
+            activation: Activation::default(),
+        };
+
+        let neurons: Vec<_> = layer
+            .neurons()
+            .map(|neuron| (neuron.bias, neuron.weights.to_vec()))
+            .collect();
+
+        assert_eq!(
+            neurons,
+            [
+                (0.1, vec![1.0, 2.0]),
+                (0.2, vec![3.0, 4.0]),
+                (0.3, vec![5.0, 6.0]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Activation, NetworkError};
+
+/// Describes a single layer of neurons.
+///
+/// The first entry of a topology is the input layer - it only passes the
+/// inputs through, so its `input_neurons` and `output_neurons` must be equal;
+/// each following entry must then take exactly as many inputs as its
+/// predecessor outputs.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LayerTopology {
+    pub input_neurons: usize,
+    pub output_neurons: usize,
+    pub activation: Activation,
+}
+
+impl LayerTopology {
+    pub fn validate(layers: &[Self]) -> Result<(), NetworkError> {
+        if layers.len() < 2 {
+            return Err(NetworkError::TopologyTooShort {
+                layers: layers.len(),
+            });
+        }
+
+        if let Some(layer) = layers
+            .iter()
+            .position(|layer| layer.input_neurons == 0 || layer.output_neurons == 0)
+        {
+            return Err(NetworkError::EmptyLayer { layer });
+        }
+
+        if layers[0].input_neurons != layers[0].output_neurons {
+            return Err(NetworkError::TopologyMismatch {
+                layer: 0,
+                expected: layers[0].output_neurons,
+                actual: layers[0].input_neurons,
+            });
+        }
+
+        for (idx, pair) in layers.windows(2).enumerate() {
+            if pair[0].output_neurons != pair[1].input_neurons {
+                return Err(NetworkError::TopologyMismatch {
+                    layer: idx + 1,
+                    expected: pair[0].output_neurons,
+                    actual: pair[1].input_neurons,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(input_neurons: usize, output_neurons: usize) -> LayerTopology {
+        LayerTopology {
+            input_neurons,
+            output_neurons,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            LayerTopology::validate(&[layer(9, 9), layer(9, 18), layer(18, 2)]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn too_short() {
+        assert_eq!(
+            LayerTopology::validate(&[]),
+            Err(NetworkError::TopologyTooShort { layers: 0 })
+        );
+
+        assert_eq!(
+            LayerTopology::validate(&[layer(3, 3)]),
+            Err(NetworkError::TopologyTooShort { layers: 1 })
+        );
+    }
+
+    #[test]
+    fn empty_layer() {
+        assert_eq!(
+            LayerTopology::validate(&[layer(3, 3), layer(3, 0)]),
+            Err(NetworkError::EmptyLayer { layer: 1 })
+        );
+    }
+
+    #[test]
+    fn mismatch() {
+        assert_eq!(
+            LayerTopology::validate(&[layer(3, 2), layer(2, 1)]),
+            Err(NetworkError::TopologyMismatch {
+                layer: 0,
+                expected: 2,
+                actual: 3,
+            })
+        );
+
+        assert_eq!(
+            LayerTopology::validate(&[layer(3, 3), layer(3, 4), layer(5, 1)]),
+            Err(NetworkError::TopologyMismatch {
+                layer: 2,
+                expected: 4,
+                actual: 5,
+            })
+        );
+    }
+}
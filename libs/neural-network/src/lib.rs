@@ -1,123 +1,720 @@
-use approx::assert_relative_eq;
-use rand::{Rng, RngCore};
+mod activation;
+mod backprop;
+mod builder;
+mod checkpoint;
+mod dot;
+mod error;
+mod gradcheck;
+mod initializer;
+mod layer;
+mod layer_topology;
+mod loss;
+mod neuron;
+mod optimizer;
+#[cfg(feature = "serde")]
+mod schema;
+mod scratch;
+mod trainer;
 
-#[derive(Debug)]
+use self::layer::*;
+pub use self::{
+    activation::*, backprop::*, builder::*, checkpoint::*, error::*, gradcheck::*, initializer::*,
+    layer_topology::*, loss::*, optimizer::*, scratch::*, trainer::*,
+};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Clone, Debug)]
 pub struct Network {
     layers: Vec<Layer>,
 }
 
-#[derive(Debug)]
-pub struct LayerTopology {
-    pub input_neurons: usize,
-    pub output_neurons: usize,
-}
-
 impl Network {
+    pub fn builder() -> NetworkBuilder {
+        NetworkBuilder::default()
+    }
+
+    /// Creates a network with random weights and biases, drawn according to
+    /// [`Initializer::default()`]; panics on invalid topology.
+    ///
+    /// # Reproducibility
+    ///
+    /// Given the same RNG state, topology and initializer, this function
+    /// (and all the other `random*` ones) returns bit-identical networks
+    /// across platforms and releases of this crate - changing that is
+    /// considered a breaking change.
+    ///
+    /// The only exception are initializers drawing from the normal
+    /// distribution ([`WeightInit::XavierNormal`], [`WeightInit::HeNormal`],
+    /// [`WeightInit::LeCunNormal`] and [`WeightInit::Orthogonal`]), which rely
+    /// on `ln()` and `cos()` - those are only guaranteed to be reproducible
+    /// on the same platform.
     pub fn random(rng: &mut dyn RngCore, layers: &[LayerTopology]) -> Self {
-        let layers = layers
-            .windows(2)
-            .map(|layers| Layer::random(rng, layers[0].output_neurons, layers[1].input_neurons))
+        Self::try_random(rng, layers)
+            .unwrap_or_else(|err| panic!("couldn't create network: {}", err))
+    }
+
+    /// Like [`Network::random()`], but uses [`ChaCha8Rng`] seeded with `seed`.
+    pub fn random_seeded(seed: u64, layers: &[LayerTopology]) -> Self {
+        Self::random(&mut ChaCha8Rng::seed_from_u64(seed), layers)
+    }
+
+    pub fn try_random(
+        rng: &mut dyn RngCore,
+        layers: &[LayerTopology],
+    ) -> Result<Self, NetworkError> {
+        Self::try_random_with(rng, layers, &Initializer::default())
+    }
+
+    /// Like [`Network::try_random_with()`], but panics on invalid topology.
+    pub fn random_with(
+        rng: &mut dyn RngCore,
+        layers: &[LayerTopology],
+        initializer: &Initializer,
+    ) -> Self {
+        Self::try_random_with(rng, layers, initializer)
+            .unwrap_or_else(|err| panic!("couldn't create network: {}", err))
+    }
+
+    pub fn try_random_with(
+        rng: &mut dyn RngCore,
+        layers: &[LayerTopology],
+        initializer: &Initializer,
+    ) -> Result<Self, NetworkError> {
+        LayerTopology::validate(layers)?;
+        initializer.validate()?;
+
+        let layers = Self::layer_specs(layers)
+            .map(|(input_size, output_size, activation)| {
+                Layer::random(rng, input_size, output_size, activation, initializer)
+            })
             .collect();
 
-        Self { layers }
+        Ok(Self::new(layers))
     }
 
-    pub fn new(layers: Vec<Layer>) -> Self {
+    pub(crate) fn new(layers: Vec<Layer>) -> Self {
         Self { layers }
     }
 
-    pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
+    /// Builds a network out of weights previously returned by
+    /// [`Network::to_weights()`] for the same topology.
+    pub fn from_weights(layers: &[LayerTopology], weights: &[f32]) -> Result<Self, NetworkError> {
+        LayerTopology::validate(layers)?;
+
+        let expected = Self::weights_len(layers);
+
+        if weights.len() != expected {
+            return Err(NetworkError::WeightsMismatch {
+                expected,
+                actual: weights.len(),
+            });
+        }
+
+        let mut weights = weights.iter().copied();
+
+        let layers = Self::layer_specs(layers)
+            .map(|(input_size, output_size, activation)| {
+                Layer::from_weights(input_size, output_size, activation, &mut weights)
+            })
+            .collect::<Option<_>>()
+            .expect("weights' length has been already checked");
+
+        Ok(Self::new(layers))
+    }
+
+    /// Returns topology this network has been created from.
+    pub fn topology(&self) -> Vec<LayerTopology> {
+        let input_neurons = self.layers.first().map_or(0, |layer| layer.input_size());
+
+        let input = LayerTopology {
+            input_neurons,
+            output_neurons: input_neurons,
+            activation: Activation::Identity,
+        };
+
+        let layers = self.layers.iter().map(|layer| LayerTopology {
+            input_neurons: layer.input_size(),
+            output_neurons: layer.output_size(),
+            activation: layer.activation,
+        });
+
+        std::iter::once(input).chain(layers).collect()
+    }
+
+    /// Returns all of the network's parameters, layer by layer and neuron by
+    /// neuron - each neuron contributes its bias followed by its weights.
+    pub fn to_weights(&self) -> Vec<f32> {
+        self.weights().collect()
+    }
+
+    /// Overwrites all of the network's parameters, using the same layout as
+    /// [`Network::to_weights()`].
+    pub fn set_weights(&mut self, weights: &[f32]) -> Result<(), NetworkError> {
+        let expected = self.layers.iter().map(Layer::weights_len).sum();
+
+        if weights.len() != expected {
+            return Err(NetworkError::WeightsMismatch {
+                expected,
+                actual: weights.len(),
+            });
+        }
+
+        let mut weights = weights;
+
+        for layer in &mut self.layers {
+            let (layer_weights, rest) = weights.split_at(layer.weights_len());
+
+            layer.set_weights(layer_weights);
+            weights = rest;
+        }
+
+        Ok(())
+    }
+
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
         self.layers
             .iter()
-            .fold(inputs, |inputs, layer| layer.propagate(inputs))
+            .flat_map(|layer| layer.neurons())
+            .flat_map(|neuron| std::iter::once(neuron.bias).chain(neuron.weights.iter().copied()))
     }
-}
 
-#[derive(Debug)]
-struct Layer {
-    neurons: Vec<Neuron>,
-}
+    /// Returns how many weights (biases included) a network of given topology
+    /// contains.
+    pub fn weights_len(layers: &[LayerTopology]) -> usize {
+        Self::layer_specs(layers)
+            .map(|(input_size, output_size, _)| (input_size + 1) * output_size)
+            .sum()
+    }
 
-impl Layer {
-    fn random(rng: &mut dyn RngCore, input_size: usize, output_size: usize) -> Self {
-        let neurons = (0..output_size)
-            .map(|_| Neuron::random(rng, input_size))
-            .collect();
+    /// Like [`Network::try_propagate()`], but panics on invalid inputs.
+    pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
+        self.try_propagate(inputs)
+            .unwrap_or_else(|err| panic!("couldn't propagate network: {}", err))
+    }
+
+    pub fn try_propagate(&self, inputs: Vec<f32>) -> Result<Vec<f32>, NetworkError> {
+        let mut inputs = inputs;
 
-        Self { neurons }
+        self.propagate_buffered(&mut inputs, &mut Vec::new())?;
+
+        Ok(inputs)
+    }
+
+    /// Like [`Network::try_propagate_batch()`], but panics on invalid inputs.
+    pub fn propagate_batch(&self, inputs: &[&[f32]]) -> Vec<Vec<f32>> {
+        self.try_propagate_batch(inputs)
+            .unwrap_or_else(|err| panic!("couldn't propagate network: {}", err))
     }
 
-    fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
-        self.neurons
+    /// Propagates many samples at once, reusing the intermediate buffers;
+    /// returns the same outputs as calling [`Network::propagate()`] for each
+    /// sample separately.
+    pub fn try_propagate_batch(&self, inputs: &[&[f32]]) -> Result<Vec<Vec<f32>>, NetworkError> {
+        let mut scratch = self.scratch();
+
+        inputs
             .iter()
-            .map(|neuron| neuron.propagate(&inputs))
+            .map(|inputs| {
+                scratch.load(inputs);
+                self.propagate_buffered(&mut scratch.current, &mut scratch.next)?;
+
+                Ok(scratch.current.clone())
+            })
             .collect()
     }
-}
 
-#[derive(Debug)]
-struct Neuron {
-    weights: Vec<f32>,
-    bias: f32,
-}
+    /// Returns how many outputs this network produces.
+    pub fn output_size(&self) -> usize {
+        self.layers.last().map_or(0, |layer| layer.output_size())
+    }
+
+    /// Returns buffers large enough for [`Network::propagate_into()`] to
+    /// never allocate.
+    pub fn scratch(&self) -> Scratch {
+        let width = self
+            .layers
+            .iter()
+            .flat_map(|layer| [layer.input_size(), layer.output_size()])
+            .max()
+            .unwrap_or(0);
+
+        Scratch::with_capacity(width)
+    }
+
+    /// Like [`Network::try_propagate_into()`], but panics on invalid inputs.
+    pub fn propagate_into(&self, inputs: &[f32], scratch: &mut Scratch, out: &mut [f32]) {
+        self.try_propagate_into(inputs, scratch, out)
+            .unwrap_or_else(|err| panic!("couldn't propagate network: {}", err))
+    }
 
-impl Neuron {
-    fn random(rng: &mut dyn RngCore, input_size: usize) -> Self {
-        let weights = (0..input_size).map(|_| rng.gen_range(-1.0..1.0)).collect();
-        let bias = rng.gen_range(-1.0..1.0);
+    /// Propagates `inputs` and writes the outputs into `out`, which must be
+    /// [`Network::output_size()`] long.
+    ///
+    /// Once `scratch` is sized for this network (see [`Network::scratch()`]),
+    /// this function doesn't allocate.
+    pub fn try_propagate_into(
+        &self,
+        inputs: &[f32],
+        scratch: &mut Scratch,
+        out: &mut [f32],
+    ) -> Result<(), NetworkError> {
+        if out.len() != self.output_size() {
+            return Err(NetworkError::OutputSizeMismatch {
+                expected: self.output_size(),
+                actual: out.len(),
+            });
+        }
 
-        Self { weights, bias }
+        scratch.load(inputs);
+        self.propagate_buffered(&mut scratch.current, &mut scratch.next)?;
+        out.copy_from_slice(&scratch.current);
+
+        Ok(())
     }
 
-    fn propagate(&self, inputs: &[f32]) -> f32 {
-        assert_eq!(inputs.len(), self.weights.len());
+    /// Propagates `current` through the network, leaving outputs in `current`
+    /// and using `next` as a temporary buffer.
+    fn propagate_buffered(
+        &self,
+        current: &mut Vec<f32>,
+        next: &mut Vec<f32>,
+    ) -> Result<(), NetworkError> {
+        self.validate_inputs(current)?;
+
+        for (layer_idx, layer) in self.layers.iter().enumerate() {
+            if current.len() != layer.input_size() {
+                return Err(NetworkError::InputSizeMismatch {
+                    layer: layer_idx,
+                    expected: layer.input_size(),
+                    actual: current.len(),
+                });
+            }
+
+            next.resize(layer.output_size(), 0.0);
+            layer.propagate(current, next);
+
+            std::mem::swap(current, next);
+        }
+
+        Ok(())
+    }
 
-        let output = inputs
+    fn validate_inputs(&self, inputs: &[f32]) -> Result<(), NetworkError> {
+        if let Some((index, &value)) = inputs
             .iter()
-            .zip(&self.weights)
-            .map(|(input, weight)| input * weight)
-            .sum::<f32>();
+            .enumerate()
+            .find(|(_, input)| !input.is_finite())
+        {
+            return Err(NetworkError::NonFiniteInput { index, value });
+        }
+
+        if let Some(layer) = self.layers.first() {
+            if inputs.len() != layer.input_size() {
+                return Err(NetworkError::InputSizeMismatch {
+                    layer: 0,
+                    expected: layer.input_size(),
+                    actual: inputs.len(),
+                });
+            }
+        }
 
-        (self.bias + output).max(0.0)
+        Ok(())
+    }
+
+    fn layer_specs(
+        layers: &[LayerTopology],
+    ) -> impl Iterator<Item = (usize, usize, Activation)> + '_ {
+        layers.windows(2).map(|layers| {
+            (
+                layers[1].input_neurons,
+                layers[1].output_neurons,
+                layers[1].activation,
+            )
+        })
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use approx::assert_relative_eq;
-//     use rand::SeedableRng;
-//     use rand_chacha::ChaCha8Rng;
-
-//     #[test]
-//     fn random() {
-//         // Because we always use the same seed, our `rng` in here will
-//         // always return the same set of values
-//         let mut rng = ChaCha8Rng::from_seed(Default::default());
-//         let neuron = Neuron::random(&mut rng, 4);
-
-//         assert_relative_eq!(neuron.bias, -0.6255188);
-
-//         assert_relative_eq!(
-//             neuron.weights.as_slice(),
-//             [0.67383957, 0.8181262, 0.26284897, 0.5238807].as_ref()
-//         );
-//     }
-// }
-
-#[test]
-fn propagate() {
-    let neuron = Neuron {
-        bias: 0.5,
-        weights: vec![-0.3, 0.8],
-    };
-
-    // Ensures `.max()` (our ReLU) works:
-    assert_relative_eq!(neuron.propagate(&[-10.0, -10.0]), 0.0,);
-
-    // `0.5` and `1.0` chosen by a fair dice roll:
-    assert_relative_eq!(
-        neuron.propagate(&[0.5, 1.0]),
-        (-0.3 * 0.5) + (0.8 * 1.0) + 0.5,
-    );
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn topology() -> Vec<LayerTopology> {
+        Network::builder()
+            .input(3)
+            .hidden(2, Activation::Relu)
+            .output(1, Activation::Tanh)
+            .topology()
+            .to_vec()
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::random(
+            &mut rng,
+            &[
+                LayerTopology {
+                    input_neurons: 3,
+                    output_neurons: 3,
+                    ..Default::default()
+                },
+                LayerTopology {
+                    input_neurons: 3,
+                    output_neurons: 5,
+                    ..Default::default()
+                },
+                LayerTopology {
+                    input_neurons: 5,
+                    output_neurons: 2,
+                    ..Default::default()
+                },
+            ],
+        );
+
+        assert_eq!(network.layers.len(), 2);
+        assert_eq!(network.layers[0].output_size(), 5);
+        assert_eq!(network.layers[1].output_size(), 2);
+        assert_eq!(network.propagate(vec![1.0, 2.0, 3.0]).len(), 2);
+    }
+
+    #[test]
+    fn random_seeded() {
+        let topology = Network::builder()
+            .input(9)
+            .hidden(18, Activation::Relu)
+            .output(2, Activation::Tanh)
+            .topology()
+            .to_vec();
+
+        let weights = Network::random_seeded(42, &topology).to_weights();
+
+        // These values must never change - see "Reproducibility" in
+        // `Network::random()`
+        assert_eq!(weights.len(), 218);
+        assert_eq!(
+            weights[..4],
+            [-0.42281246, -0.55183864, 0.36379218, -0.7072277]
+        );
+        assert_eq!(weights[215..], [-0.49003196, -0.5340862, -0.44022298]);
+        assert_eq!(weights.iter().sum::<f32>(), 3.7208602);
+
+        assert_eq!(
+            weights,
+            Network::random(&mut ChaCha8Rng::seed_from_u64(42), &topology).to_weights()
+        );
+
+        assert_ne!(weights, Network::random_seeded(43, &topology).to_weights());
+    }
+
+    #[test]
+    fn random_with_default_initializer() {
+        let network_a =
+            Network::random(&mut ChaCha8Rng::from_seed(Default::default()), &topology());
+
+        let network_b = Network::random_with(
+            &mut ChaCha8Rng::from_seed(Default::default()),
+            &topology(),
+            &Initializer::default(),
+        );
+
+        assert_eq!(network_a.to_weights(), network_b.to_weights());
+    }
+
+    #[test]
+    fn random_with_initializer() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::random_with(
+            &mut rng,
+            &topology(),
+            &Initializer {
+                weights: WeightInit::XavierUniform,
+                biases: BiasInit::Zeros,
+            },
+        );
+
+        let limit = (6.0f32 / 5.0).sqrt();
+
+        assert!(network.layers[0].weights.iter().all(|w| w.abs() < limit));
+        assert!(network
+            .layers
+            .iter()
+            .all(|layer| layer.biases.iter().all(|&b| b == 0.0)));
+    }
+
+    #[test]
+    fn try_random_rejects_invalid_topology() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        assert_eq!(
+            Network::try_random(&mut rng, &topology()[..1]).unwrap_err(),
+            NetworkError::TopologyTooShort { layers: 1 }
+        );
+
+        assert_eq!(
+            Network::try_random(&mut rng, &[topology()[0], topology()[2]]).unwrap_err(),
+            NetworkError::TopologyMismatch {
+                layer: 1,
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn try_random_with_rejects_invalid_initializer() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut random = |initializer| {
+            Network::try_random_with(&mut rng, &topology(), &initializer).unwrap_err()
+        };
+
+        assert_eq!(
+            random(Initializer {
+                weights: WeightInit::Uniform { limit: 0.0 },
+                ..Default::default()
+            }),
+            NetworkError::InvalidInitializer {
+                parameter: "weights.limit",
+                value: 0.0,
+                reason: "must be positive",
+            }
+        );
+
+        assert_eq!(
+            random(Initializer {
+                biases: BiasInit::Uniform { limit: -1.0 },
+                ..Default::default()
+            }),
+            NetworkError::InvalidInitializer {
+                parameter: "biases.limit",
+                value: -1.0,
+                reason: "must be positive",
+            }
+        );
+
+        assert_eq!(
+            random(Initializer {
+                weights: WeightInit::Orthogonal {
+                    gain: f32::INFINITY
+                },
+                ..Default::default()
+            })
+            .to_string(),
+            "initializer's `weights.gain` must be finite, but got inf"
+        );
+    }
+
+    #[test]
+    fn weights_roundtrip() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let network = Network::random(&mut rng, &topology());
+        let weights = network.to_weights();
+
+        assert_eq!(weights.len(), Network::weights_len(&topology()));
+
+        let restored = Network::from_weights(&topology(), &weights).unwrap();
+
+        assert_eq!(restored.to_weights(), weights);
+
+        assert_eq!(
+            restored.propagate(vec![0.5, -0.2, 1.0]),
+            network.propagate(vec![0.5, -0.2, 1.0]),
+        );
+    }
+
+    #[test]
+    fn set_weights() {
+        let mut network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+        let weights: Vec<_> = (0..11).map(|i| i as f32).collect();
+
+        network.set_weights(&weights).unwrap();
+
+        assert_eq!(network.to_weights(), weights);
+
+        assert_eq!(
+            network.set_weights(&weights[1..]).unwrap_err(),
+            NetworkError::WeightsMismatch {
+                expected: 11,
+                actual: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn weights_layout() {
+        let weights = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1];
+        let network = Network::from_weights(&topology(), &weights).unwrap();
+
+        assert_eq!(network.layers.len(), 2);
+        assert_relative_eq!(network.layers[0].biases.as_slice(), [0.1, 0.5].as_ref());
+        assert_relative_eq!(
+            network.layers[0].weights.as_slice(),
+            [0.2, 0.3, 0.4, 0.6, 0.7, 0.8].as_ref()
+        );
+        assert_relative_eq!(network.layers[1].biases.as_slice(), [0.9].as_ref());
+    }
+
+    #[test]
+    fn propagate_with_activations() {
+        let weights = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, -1.0];
+        let network = Network::from_weights(&topology(), &weights).unwrap();
+
+        // Hidden layer is ReLU, output layer is tanh - so the output can be
+        // negative, but only through the second layer
+        let outputs = network.propagate(vec![2.0, 3.0, 0.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [-0.99990916].as_ref());
+
+        let outputs = network.propagate(vec![-2.0, -3.0, 0.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [0.0].as_ref());
+    }
+
+    #[test]
+    fn topology_roundtrip() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        assert_eq!(network.topology(), topology());
+    }
+
+    #[test]
+    fn propagate_batch() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::builder()
+            .input(3)
+            .hidden(5, Activation::Relu)
+            .hidden(4, Activation::Sigmoid)
+            .output(2, Activation::Softmax)
+            .random(&mut rng)
+            .unwrap();
+
+        let inputs = [
+            vec![0.5, -0.2, 1.0],
+            vec![0.0, 0.0, 0.0],
+            vec![-3.0, 2.5, 0.1],
+        ];
+
+        let inputs: Vec<_> = inputs.iter().map(|inputs| inputs.as_slice()).collect();
+        let outputs = network.propagate_batch(&inputs);
+
+        assert_eq!(outputs.len(), inputs.len());
+
+        for (inputs, outputs) in inputs.iter().zip(outputs) {
+            assert_eq!(outputs, network.propagate(inputs.to_vec()));
+        }
+
+        assert!(network.propagate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn try_propagate_batch_rejects_invalid_sample() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        assert_eq!(
+            network
+                .try_propagate_batch(&[&[1.0, 2.0, 3.0], &[1.0]])
+                .unwrap_err(),
+            NetworkError::InputSizeMismatch {
+                layer: 0,
+                expected: 3,
+                actual: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn propagate_into() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::builder()
+            .input(3)
+            .hidden(5, Activation::Relu)
+            .output(2, Activation::Tanh)
+            .random(&mut rng)
+            .unwrap();
+
+        let mut scratch = network.scratch();
+        let mut out = [0.0; 2];
+
+        for inputs in [[0.5, -0.2, 1.0], [-3.0, 2.5, 0.1]] {
+            network.propagate_into(&inputs, &mut scratch, &mut out);
+
+            assert_eq!(out.to_vec(), network.propagate(inputs.to_vec()));
+        }
+    }
+
+    #[test]
+    fn try_propagate_into_rejects_wrong_output_size() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        assert_eq!(
+            network
+                .try_propagate_into(&[1.0, 2.0, 3.0], &mut network.scratch(), &mut [0.0; 2])
+                .unwrap_err(),
+            NetworkError::OutputSizeMismatch {
+                expected: 1,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn try_propagate_rejects_wrong_input_size() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        assert_eq!(
+            network.try_propagate(vec![1.0, 2.0]).unwrap_err(),
+            NetworkError::InputSizeMismatch {
+                layer: 0,
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn try_propagate_rejects_non_finite_inputs() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        assert_eq!(
+            network
+                .try_propagate(vec![1.0, f32::INFINITY, 0.0])
+                .unwrap_err(),
+            NetworkError::NonFiniteInput {
+                index: 1,
+                value: f32::INFINITY,
+            }
+        );
+
+        assert!(matches!(
+            network.try_propagate(vec![f32::NAN, 0.0, 0.0]),
+            Err(NetworkError::NonFiniteInput { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "layer #0 expects 3 inputs, but got 1")]
+    fn propagate_panics_on_wrong_input_size() {
+        let network = Network::from_weights(&topology(), &[0.0; 11]).unwrap();
+
+        network.propagate(vec![1.0]);
+    }
+
+    #[test]
+    fn from_weights_rejects_wrong_length() {
+        assert_eq!(
+            Network::from_weights(&topology(), &[0.0; 10]).unwrap_err(),
+            NetworkError::WeightsMismatch {
+                expected: 11,
+                actual: 10
+            }
+        );
+    }
 }
@@ -0,0 +1,210 @@
+/// Loss functions, averaged over all of the outputs - apart from
+/// [`Loss::SoftmaxCrossEntropy`], which is summed over them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Loss {
+    /// Mean squared error.
+    #[default]
+    Mse,
+    /// Mean absolute error.
+    Mae,
+    /// Quadratic for errors smaller than `delta`, linear otherwise; `delta`
+    /// must not be negative.
+    Huber { delta: f32 },
+    /// Expects outputs in `(0, 1)`, e.g. from a sigmoid layer.
+    BinaryCrossEntropy,
+    /// Expects raw scores (e.g. from an identity layer), which get turned into
+    /// probabilities through softmax; targets should sum up to one.
+    ///
+    /// Being a single per-sample loss over a probability distribution, it's
+    /// summed over the outputs instead of averaged.
+    SoftmaxCrossEntropy,
+}
+
+impl Loss {
+    /// Returns the loss and its gradient with respect to `outputs`.
+    pub fn compute(&self, outputs: &[f32], targets: &[f32]) -> (f32, Vec<f32>) {
+        assert_eq!(outputs.len(), targets.len());
+
+        let n = outputs.len() as f32;
+        let errors = outputs.iter().zip(targets).map(|(y, t)| (y - t, y, t));
+
+        match *self {
+            Self::Mse => {
+                let loss = errors.clone().map(|(e, _, _)| e * e).sum::<f32>() / n;
+                let grads = errors.map(|(e, _, _)| 2.0 * e / n).collect();
+
+                (loss, grads)
+            }
+
+            Self::Mae => {
+                let loss = errors.clone().map(|(e, _, _)| e.abs()).sum::<f32>() / n;
+                let grads = errors.map(|(e, _, _)| sign(e) / n).collect();
+
+                (loss, grads)
+            }
+
+            Self::Huber { delta } => {
+                assert!(
+                    delta >= 0.0,
+                    "Huber loss' delta must be non-negative, but got {}",
+                    delta
+                );
+
+                let loss = errors
+                    .clone()
+                    .map(|(e, _, _)| {
+                        if e.abs() <= delta {
+                            0.5 * e * e
+                        } else {
+                            delta * (e.abs() - 0.5 * delta)
+                        }
+                    })
+                    .sum::<f32>()
+                    / n;
+
+                let grads = errors.map(|(e, _, _)| e.clamp(-delta, delta) / n).collect();
+
+                (loss, grads)
+            }
+
+            Self::BinaryCrossEntropy => {
+                // Keeps `ln()` away from zero
+                let clamp = |y: f32| y.clamp(1e-7, 1.0 - 1e-7);
+
+                let loss = errors
+                    .clone()
+                    .map(|(_, &y, &t)| {
+                        let y = clamp(y);
+                        -(t * y.ln() + (1.0 - t) * (1.0 - y).ln())
+                    })
+                    .sum::<f32>()
+                    / n;
+
+                let grads = errors
+                    .map(|(_, &y, &t)| {
+                        let y = clamp(y);
+                        (y - t) / (y * (1.0 - y)) / n
+                    })
+                    .collect();
+
+                (loss, grads)
+            }
+
+            Self::SoftmaxCrossEntropy => {
+                let max = outputs.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                let log_sum = outputs.iter().map(|y| (y - max).exp()).sum::<f32>().ln() + max;
+
+                let loss = outputs
+                    .iter()
+                    .zip(targets)
+                    .map(|(y, t)| -t * (y - log_sum))
+                    .sum::<f32>();
+
+                let grads = outputs
+                    .iter()
+                    .zip(targets)
+                    .map(|(y, t)| (y - log_sum).exp() - t)
+                    .collect();
+
+                (loss, grads)
+            }
+        }
+    }
+}
+
+fn sign(x: f32) -> f32 {
+    if x > 0.0 {
+        1.0
+    } else if x < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn check_gradient(loss: Loss, outputs: &[f32], targets: &[f32]) {
+        let (_, grads) = loss.compute(outputs, targets);
+
+        for i in 0..outputs.len() {
+            let eps = 1e-3;
+            let value = |delta: f32| {
+                let mut outputs = outputs.to_vec();
+                outputs[i] += delta;
+                loss.compute(&outputs, targets).0
+            };
+
+            let numeric = (value(eps) - value(-eps)) / (2.0 * eps);
+
+            assert_relative_eq!(grads[i], numeric, epsilon = 1e-2, max_relative = 1e-2);
+        }
+    }
+
+    #[test]
+    fn mse() {
+        let (loss, _) = Loss::Mse.compute(&[1.0, 2.0], &[0.0, 4.0]);
+
+        assert_relative_eq!(loss, 2.5);
+        check_gradient(Loss::Mse, &[0.3, -1.2, 2.0], &[0.0, 1.0, 2.5]);
+    }
+
+    #[test]
+    fn mae() {
+        let (loss, _) = Loss::Mae.compute(&[1.0, 2.0], &[0.0, 4.0]);
+
+        assert_relative_eq!(loss, 1.5);
+        check_gradient(Loss::Mae, &[0.3, -1.2, 2.0], &[0.0, 1.0, 2.5]);
+    }
+
+    #[test]
+    fn huber() {
+        let huber = Loss::Huber { delta: 1.0 };
+        let (loss, _) = huber.compute(&[0.5, 3.0], &[0.0, 0.0]);
+
+        assert_relative_eq!(loss, (0.125 + 2.5) / 2.0);
+        check_gradient(huber, &[0.3, -1.2, 2.0], &[0.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Huber loss' delta must be non-negative, but got NaN")]
+    fn huber_with_invalid_delta() {
+        Loss::Huber { delta: f32::NAN }.compute(&[0.5], &[0.0]);
+    }
+
+    #[test]
+    fn binary_cross_entropy() {
+        let (loss, _) = Loss::BinaryCrossEntropy.compute(&[0.5], &[1.0]);
+
+        assert_relative_eq!(loss, 2.0f32.ln());
+        check_gradient(Loss::BinaryCrossEntropy, &[0.2, 0.7, 0.9], &[0.0, 1.0, 0.5]);
+
+        // Saturated outputs stay finite
+        let (loss, grads) = Loss::BinaryCrossEntropy.compute(&[0.0, 1.0], &[1.0, 0.0]);
+
+        assert!(loss.is_finite());
+        assert!(grads.iter().all(|grad| grad.is_finite()));
+    }
+
+    #[test]
+    fn softmax_cross_entropy() {
+        let (loss, grads) = Loss::SoftmaxCrossEntropy.compute(&[0.0, 0.0], &[1.0, 0.0]);
+
+        assert_relative_eq!(loss, 2.0f32.ln());
+        assert_relative_eq!(grads.as_slice(), [-0.5, 0.5].as_ref());
+
+        check_gradient(
+            Loss::SoftmaxCrossEntropy,
+            &[0.3, -1.2, 2.0],
+            &[0.2, 0.0, 0.8],
+        );
+
+        // Large scores must not overflow
+        let (loss, _) = Loss::SoftmaxCrossEntropy.compute(&[1000.0, 0.0], &[1.0, 0.0]);
+
+        assert_relative_eq!(loss, 0.0);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::dot;
+
+/// A single row of a [`crate::layer::Layer`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Neuron<'a> {
+    pub(crate) weights: &'a [f32],
+    pub(crate) bias: f32,
+}
+
+impl Neuron<'_> {
+    pub(crate) fn propagate(&self, inputs: &[f32]) -> f32 {
+        assert_eq!(inputs.len(), self.weights.len());
+
+        self.bias + dot::dot(inputs, self.weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn propagate() {
+        let neuron = Neuron {
+            bias: 0.5,
+            weights: &[-0.3, 0.8],
+        };
+
+        // Activation is applied by the layer, so negative outputs go through:
+        assert_relative_eq!(neuron.propagate(&[-10.0, -10.0]), -4.5);
+
+        assert_relative_eq!(
+            neuron.propagate(&[0.5, 1.0]),
+            (-0.3 * 0.5) + (0.8 * 1.0) + 0.5,
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Optimizers turn gradients returned by [`crate::Network::backward()`] into
+//! parameter updates; their per-parameter state uses the same layout as
+//! [`crate::Network::to_weights()`].
+
+use std::f32::consts::PI;
+
+pub trait Optimizer {
+    /// Updates `params` given gradients of the loss with respect to them.
+    fn step(&mut self, params: &mut [f32], grads: &[f32]);
+}
+
+/// Learning rate schedule; `step` counts from zero.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Schedule {
+    #[default]
+    Constant,
+    /// Multiplies the learning rate by `gamma` every `step_size` steps; with
+    /// `step_size` of zero it stays constant.
+    Step { step_size: usize, gamma: f32 },
+    /// Anneals the learning rate down to `min_learning_rate` over `steps`
+    /// steps, following half of a cosine wave.
+    Cosine {
+        steps: usize,
+        min_learning_rate: f32,
+    },
+    /// Linearly increases the learning rate over `steps` steps and then
+    /// continues with `then`.
+    Warmup { steps: usize, then: Box<Schedule> },
+}
+
+impl Schedule {
+    pub fn learning_rate(&self, base: f32, step: usize) -> f32 {
+        match self {
+            Self::Constant => base,
+
+            Self::Step { step_size, gamma } => {
+                base * gamma.powi(step.checked_div(*step_size).unwrap_or(0) as i32)
+            }
+
+            Self::Cosine {
+                steps,
+                min_learning_rate,
+            } => {
+                let progress = step.min(*steps) as f32 / (*steps).max(1) as f32;
+
+                min_learning_rate + (base - min_learning_rate) * (1.0 + (PI * progress).cos()) / 2.0
+            }
+
+            Self::Warmup { steps, then } => {
+                if step < *steps {
+                    base * (step + 1) as f32 / *steps as f32
+                } else {
+                    then.learning_rate(base, step - steps)
+                }
+            }
+        }
+    }
+}
+
+/// Stochastic gradient descent, optionally with (Nesterov) momentum.
+#[derive(Clone, Debug)]
+pub struct Sgd {
+    learning_rate: f32,
+    schedule: Schedule,
+    momentum: f32,
+    nesterov: bool,
+    velocity: Vec<f32>,
+    t: usize,
+}
+
+impl Sgd {
+    pub fn new(learning_rate: f32) -> Self {
+        Self {
+            learning_rate,
+            schedule: Schedule::Constant,
+            momentum: 0.0,
+            nesterov: false,
+            velocity: Vec::new(),
+            t: 0,
+        }
+    }
+
+    pub fn momentum(mut self, momentum: f32) -> Self {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn nesterov(mut self, nesterov: bool) -> Self {
+        self.nesterov = nesterov;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self, params: &mut [f32], grads: &[f32]) {
+        let lr = self.schedule.learning_rate(self.learning_rate, self.t);
+
+        init_state(&mut self.velocity, params, grads);
+
+        for ((param, &grad), velocity) in params.iter_mut().zip(grads).zip(&mut self.velocity) {
+            *velocity = self.momentum * *velocity + grad;
+
+            *param -= if self.nesterov {
+                lr * (grad + self.momentum * *velocity)
+            } else {
+                lr * *velocity
+            };
+        }
+
+        self.t += 1;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RmsProp {
+    learning_rate: f32,
+    schedule: Schedule,
+    decay: f32,
+    epsilon: f32,
+    mean_square: Vec<f32>,
+    t: usize,
+}
+
+impl RmsProp {
+    pub fn new(learning_rate: f32) -> Self {
+        Self {
+            learning_rate,
+            schedule: Schedule::Constant,
+            decay: 0.9,
+            epsilon: 1e-8,
+            mean_square: Vec::new(),
+            t: 0,
+        }
+    }
+
+    pub fn decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    pub fn epsilon(mut self, epsilon: f32) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+}
+
+impl Optimizer for RmsProp {
+    fn step(&mut self, params: &mut [f32], grads: &[f32]) {
+        let lr = self.schedule.learning_rate(self.learning_rate, self.t);
+
+        init_state(&mut self.mean_square, params, grads);
+
+        for ((param, &grad), mean_square) in params.iter_mut().zip(grads).zip(&mut self.mean_square)
+        {
+            *mean_square = self.decay * *mean_square + (1.0 - self.decay) * grad * grad;
+            *param -= lr * grad / (mean_square.sqrt() + self.epsilon);
+        }
+
+        self.t += 1;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Adam {
+    learning_rate: f32,
+    schedule: Schedule,
+    beta1: f32,
+    beta2: f32,
+    epsilon: f32,
+    m: Vec<f32>,
+    v: Vec<f32>,
+    t: usize,
+}
+
+impl Adam {
+    pub fn new(learning_rate: f32) -> Self {
+        Self {
+            learning_rate,
+            schedule: Schedule::Constant,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            m: Vec::new(),
+            v: Vec::new(),
+            t: 0,
+        }
+    }
+
+    pub fn betas(mut self, beta1: f32, beta2: f32) -> Self {
+        self.beta1 = beta1;
+        self.beta2 = beta2;
+        self
+    }
+
+    pub fn epsilon(mut self, epsilon: f32) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    fn current_learning_rate(&self) -> f32 {
+        self.schedule.learning_rate(self.learning_rate, self.t)
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, params: &mut [f32], grads: &[f32]) {
+        let lr = self.current_learning_rate();
+
+        init_state(&mut self.m, params, grads);
+        init_state(&mut self.v, params, grads);
+
+        self.t += 1;
+
+        let bias1 = 1.0 - self.beta1.powi(self.t as i32);
+        let bias2 = 1.0 - self.beta2.powi(self.t as i32);
+
+        for (((param, &grad), m), v) in params
+            .iter_mut()
+            .zip(grads)
+            .zip(&mut self.m)
+            .zip(&mut self.v)
+        {
+            *m = self.beta1 * *m + (1.0 - self.beta1) * grad;
+            *v = self.beta2 * *v + (1.0 - self.beta2) * grad * grad;
+
+            *param -= lr * (*m / bias1) / ((*v / bias2).sqrt() + self.epsilon);
+        }
+    }
+}
+
+/// Adam with decoupled weight decay.
+#[derive(Clone, Debug)]
+pub struct AdamW {
+    adam: Adam,
+    weight_decay: f32,
+}
+
+impl AdamW {
+    pub fn new(learning_rate: f32) -> Self {
+        Self {
+            adam: Adam::new(learning_rate),
+            weight_decay: 0.01,
+        }
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f32) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+
+    pub fn betas(mut self, beta1: f32, beta2: f32) -> Self {
+        self.adam = self.adam.betas(beta1, beta2);
+        self
+    }
+
+    pub fn epsilon(mut self, epsilon: f32) -> Self {
+        self.adam = self.adam.epsilon(epsilon);
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.adam = self.adam.schedule(schedule);
+        self
+    }
+}
+
+impl Optimizer for AdamW {
+    fn step(&mut self, params: &mut [f32], grads: &[f32]) {
+        let decay = 1.0 - self.adam.current_learning_rate() * self.weight_decay;
+
+        params.iter_mut().for_each(|param| *param *= decay);
+
+        self.adam.step(params, grads);
+    }
+}
+
+fn init_state(state: &mut Vec<f32>, params: &[f32], grads: &[f32]) {
+    assert_eq!(params.len(), grads.len());
+
+    if state.is_empty() {
+        state.resize(params.len(), 0.0);
+    }
+
+    assert_eq!(
+        state.len(),
+        params.len(),
+        "optimizer has been used with parameters of a different size"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const TARGET: [f32; 3] = [1.0, -2.0, 0.5];
+    const SCALES: [f32; 3] = [1.0, 10.0, 0.1];
+
+    /// Minimizes an ill-conditioned quadratic, returning final parameters.
+    fn minimize(optimizer: &mut dyn Optimizer, steps: usize) -> Vec<f32> {
+        let mut params = vec![0.0; 3];
+
+        for _ in 0..steps {
+            let grads: Vec<_> = params
+                .iter()
+                .zip(TARGET)
+                .zip(SCALES)
+                .map(|((param, target), scale)| 2.0 * scale * (param - target))
+                .collect();
+
+            optimizer.step(&mut params, &grads);
+        }
+
+        params
+    }
+
+    #[test]
+    fn sgd() {
+        let params = minimize(&mut Sgd::new(0.04), 2000);
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn sgd_momentum() {
+        let params = minimize(&mut Sgd::new(0.01).momentum(0.9), 1000);
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn sgd_nesterov() {
+        let params = minimize(&mut Sgd::new(0.01).momentum(0.9).nesterov(true), 1000);
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn sgd_momentum_is_faster() {
+        let distance = |params: Vec<f32>| {
+            params
+                .iter()
+                .zip(TARGET)
+                .map(|(param, target)| (param - target).abs())
+                .sum::<f32>()
+        };
+
+        let plain = distance(minimize(&mut Sgd::new(0.01), 100));
+        let momentum = distance(minimize(&mut Sgd::new(0.01).momentum(0.9), 100));
+
+        assert!(momentum < plain, "{} vs {}", momentum, plain);
+    }
+
+    #[test]
+    fn rms_prop() {
+        let params = minimize(
+            &mut RmsProp::new(0.01).schedule(Schedule::Step {
+                step_size: 500,
+                gamma: 0.1,
+            }),
+            2000,
+        );
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn adam() {
+        let params = minimize(
+            &mut Adam::new(0.05).schedule(Schedule::Cosine {
+                steps: 2000,
+                min_learning_rate: 0.0,
+            }),
+            2000,
+        );
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn adam_w() {
+        let params = minimize(&mut AdamW::new(0.05).weight_decay(0.0), 2000);
+
+        assert_relative_eq!(params.as_slice(), TARGET.as_ref(), epsilon = 1e-3);
+
+        // Weight decay pulls the parameters towards zero
+        let params = minimize(&mut AdamW::new(0.05).weight_decay(0.5), 2000);
+
+        assert!(params[0] < TARGET[0] - 1e-2, "{:?}", params);
+        assert!(params[0] > 0.0, "{:?}", params);
+    }
+
+    #[test]
+    fn deterministic() {
+        assert_eq!(
+            minimize(&mut Adam::new(0.05), 100),
+            minimize(&mut Adam::new(0.05), 100)
+        );
+    }
+
+    #[test]
+    fn schedules() {
+        assert_relative_eq!(Schedule::Constant.learning_rate(0.1, 1000), 0.1);
+
+        let step = Schedule::Step {
+            step_size: 10,
+            gamma: 0.5,
+        };
+
+        assert_relative_eq!(step.learning_rate(0.1, 9), 0.1);
+        assert_relative_eq!(step.learning_rate(0.1, 10), 0.05);
+        assert_relative_eq!(step.learning_rate(0.1, 25), 0.025);
+
+        let never = Schedule::Step {
+            step_size: 0,
+            gamma: 0.5,
+        };
+
+        assert_relative_eq!(never.learning_rate(0.1, 0), 0.1);
+        assert_relative_eq!(never.learning_rate(0.1, 25), 0.1);
+
+        let cosine = Schedule::Cosine {
+            steps: 100,
+            min_learning_rate: 0.01,
+        };
+
+        assert_relative_eq!(cosine.learning_rate(0.1, 0), 0.1);
+        assert_relative_eq!(cosine.learning_rate(0.1, 50), 0.055);
+        assert_relative_eq!(cosine.learning_rate(0.1, 100), 0.01);
+        assert_relative_eq!(cosine.learning_rate(0.1, 200), 0.01);
+
+        let warmup = Schedule::Warmup {
+            steps: 4,
+            then: Box::new(step),
+        };
+
+        assert_relative_eq!(warmup.learning_rate(0.1, 0), 0.025);
+        assert_relative_eq!(warmup.learning_rate(0.1, 3), 0.1);
+        assert_relative_eq!(warmup.learning_rate(0.1, 14), 0.05);
+    }
+}
@@ -0,0 +1,151 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! Networks are serialized through a versioned representation that contains
+//! the topology (with activations) and the flat weights, as returned by
+//! [`Network::to_weights()`]:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "topology": [
+//!     { "input_neurons": 2, "output_neurons": 2, "activation": { "type": "identity" } },
+//!     { "input_neurons": 2, "output_neurons": 1, "activation": { "type": "tanh" } }
+//!   ],
+//!   "weights": [0.1, 0.2, 0.3]
+//! }
+//! ```
+//!
+//! When the schema changes, `VERSION` gets bumped and older versions keep on
+//! being migrated during deserialization.
+
+use crate::{LayerTopology, Network};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct NetworkRepr {
+    version: u32,
+    topology: Vec<LayerTopology>,
+    weights: Vec<f32>,
+}
+
+/// Deserializes a unit variant of an internally tagged enum, rejecting any
+/// keys besides the tag - serde ignores `deny_unknown_fields` for unit
+/// variants, which would silently drop e.g. `{ type = "tanh", alpha = 0.3 }`.
+pub(crate) fn unit_variant<'de, D>(deserializer: D) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Empty {}
+
+    Empty::deserialize(deserializer).map(|Empty {}| ())
+}
+
+impl Serialize for Network {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        NetworkRepr {
+            version: VERSION,
+            topology: self.topology(),
+            weights: self.to_weights(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Network {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = NetworkRepr::deserialize(deserializer)?;
+
+        if repr.version != VERSION {
+            return Err(de::Error::custom(format_args!(
+                "unsupported network schema version: {} (expected {})",
+                repr.version, VERSION
+            )));
+        }
+
+        Network::from_weights(&repr.topology, &repr.weights).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Activation;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let network = Network::builder()
+            .input(3)
+            .hidden(4, Activation::LeakyRelu { alpha: 0.01 })
+            .output(2, Activation::Softmax)
+            .random(&mut rng)
+            .unwrap();
+
+        let json = serde_json::to_string(&network).unwrap();
+        let restored: Network = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.topology(), network.topology());
+        assert_eq!(restored.to_weights(), network.to_weights());
+    }
+
+    #[test]
+    fn deserialize_v1() {
+        let network: Network = serde_json::from_str(
+            r#"{
+                "version": 1,
+                "topology": [
+                    { "input_neurons": 2, "output_neurons": 2, "activation": { "type": "identity" } },
+                    { "input_neurons": 2, "output_neurons": 1, "activation": { "type": "tanh" } }
+                ],
+                "weights": [0.1, 0.2, 0.3]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(network.topology()[1].activation, Activation::Tanh);
+        assert_eq!(network.to_weights(), [0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn deserialize_rejects_unknown_version() {
+        let err =
+            serde_json::from_str::<Network>(r#"{ "version": 2, "topology": [], "weights": [] }"#)
+                .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("unsupported network schema version: 2"));
+    }
+
+    #[test]
+    fn deserialize_rejects_mismatched_weights() {
+        let err = serde_json::from_str::<Network>(
+            r#"{
+                "version": 1,
+                "topology": [
+                    { "input_neurons": 2, "output_neurons": 2, "activation": { "type": "identity" } },
+                    { "input_neurons": 2, "output_neurons": 1, "activation": { "type": "tanh" } }
+                ],
+                "weights": [0.1]
+            }"#,
+        )
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("topology requires 3 weights, but got 1"));
+    }
+}
@@ -0,0 +1,22 @@
+/// Intermediate buffers for [`crate::Network::propagate_into()`].
+#[derive(Clone, Debug, Default)]
+pub struct Scratch {
+    pub(crate) current: Vec<f32>,
+    pub(crate) next: Vec<f32>,
+}
+
+impl Scratch {
+    /// Creates buffers that fit layers of up to `width` neurons without
+    /// reallocating.
+    pub fn with_capacity(width: usize) -> Self {
+        Self {
+            current: Vec::with_capacity(width),
+            next: Vec::with_capacity(width),
+        }
+    }
+
+    pub(crate) fn load(&mut self, inputs: &[f32]) {
+        self.current.clear();
+        self.current.extend_from_slice(inputs);
+    }
+}
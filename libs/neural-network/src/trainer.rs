@@ -0,0 +1,236 @@
+use crate::{Loss, Network, Optimizer, Sgd};
+use rand::seq::SliceRandom;
+use rand::RngCore;
+
+/// Supervised mini-batch training.
+#[derive(Clone, Debug)]
+pub struct Trainer<O = Sgd> {
+    optimizer: O,
+    loss: Loss,
+    batch_size: usize,
+    epochs: usize,
+}
+
+impl<O> Trainer<O>
+where
+    O: Optimizer,
+{
+    pub fn new(optimizer: O) -> Self {
+        Self {
+            optimizer,
+            loss: Loss::Mse,
+            batch_size: 32,
+            epochs: 1,
+        }
+    }
+
+    pub fn loss(mut self, loss: Loss) -> Self {
+        self.loss = loss;
+        self
+    }
+
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0);
+
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn epochs(mut self, epochs: usize) -> Self {
+        self.epochs = epochs;
+        self
+    }
+
+    /// Trains `network` on given `(inputs, targets)` pairs, shuffling them
+    /// before each epoch; returns the mean loss of the last epoch.
+    pub fn train(
+        &mut self,
+        rng: &mut dyn RngCore,
+        network: &mut Network,
+        dataset: &[(Vec<f32>, Vec<f32>)],
+    ) -> f32 {
+        let mut order: Vec<_> = (0..dataset.len()).collect();
+        let mut weights = network.to_weights();
+        let mut loss = 0.0;
+
+        for _ in 0..self.epochs {
+            order.shuffle(rng);
+            loss = 0.0;
+
+            for batch in order.chunks(self.batch_size) {
+                let mut grads = vec![0.0; weights.len()];
+
+                for &idx in batch {
+                    let (inputs, targets) = &dataset[idx];
+                    let trace = network.forward(inputs);
+                    let (sample_loss, output_grads) = self.loss.compute(trace.outputs(), targets);
+
+                    loss += sample_loss;
+
+                    for (grad, sample_grad) in grads
+                        .iter_mut()
+                        .zip(network.backward(&trace, &output_grads))
+                    {
+                        *grad += sample_grad;
+                    }
+                }
+
+                let batch_len = batch.len() as f32;
+
+                grads.iter_mut().for_each(|grad| *grad /= batch_len);
+
+                self.optimizer.step(&mut weights, &grads);
+
+                network
+                    .set_weights(&weights)
+                    .expect("weights' layout doesn't change during training");
+            }
+
+            loss /= dataset.len().max(1) as f32;
+        }
+
+        loss
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Activation, Adam};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn xor() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut network = Network::builder()
+            .input(2)
+            .hidden(4, Activation::Tanh)
+            .output(1, Activation::Sigmoid)
+            .random(&mut rng)
+            .unwrap();
+
+        let dataset = vec![
+            (vec![0.0, 0.0], vec![0.0]),
+            (vec![0.0, 1.0], vec![1.0]),
+            (vec![1.0, 0.0], vec![1.0]),
+            (vec![1.0, 1.0], vec![0.0]),
+        ];
+
+        let loss = Trainer::new(Sgd::new(0.5))
+            .batch_size(4)
+            .epochs(5000)
+            .train(&mut rng, &mut network, &dataset);
+
+        assert!(loss < 0.01, "loss = {}", loss);
+
+        for (inputs, targets) in &dataset {
+            let output = network.propagate(inputs.clone())[0];
+
+            assert!(
+                (output - targets[0]).abs() < 0.2,
+                "{:?} -> {}",
+                inputs,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn regression() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut network = Network::builder()
+            .input(1)
+            .hidden(8, Activation::Tanh)
+            .output(1, Activation::Identity)
+            .random(&mut rng)
+            .unwrap();
+
+        // y = x² on [-1, 1]
+        let dataset: Vec<_> = (0..64)
+            .map(|_| {
+                let x = rng.gen_range(-1.0..1.0);
+                (vec![x], vec![x * x])
+            })
+            .collect();
+
+        let loss = Trainer::new(Sgd::new(0.1)).batch_size(8).epochs(500).train(
+            &mut rng,
+            &mut network,
+            &dataset,
+        );
+
+        assert!(loss < 0.005, "loss = {}", loss);
+    }
+
+    #[test]
+    fn classification() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut network = Network::builder()
+            .input(2)
+            .hidden(8, Activation::Tanh)
+            .output(3, Activation::Identity)
+            .random(&mut rng)
+            .unwrap();
+
+        // Which third of the plane (split by angle) given point lies in
+        let dataset: Vec<_> = (0..90)
+            .map(|i| {
+                let angle = (i as f32) / 90.0 * std::f32::consts::TAU;
+                let mut targets = vec![0.0; 3];
+
+                targets[i / 30] = 1.0;
+                (vec![angle.cos(), angle.sin()], targets)
+            })
+            .collect();
+
+        Trainer::new(Adam::new(0.02))
+            .loss(Loss::SoftmaxCrossEntropy)
+            .batch_size(10)
+            .epochs(300)
+            .train(&mut rng, &mut network, &dataset);
+
+        let correct = dataset
+            .iter()
+            .filter(|(inputs, targets)| {
+                let outputs = network.propagate(inputs.clone());
+                let predicted = (0..3)
+                    .max_by(|&a, &b| outputs[a].total_cmp(&outputs[b]))
+                    .unwrap();
+
+                targets[predicted] == 1.0
+            })
+            .count();
+
+        assert!(correct >= 85, "correct = {}", correct);
+    }
+
+    #[test]
+    fn xor_with_adam() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut network = Network::builder()
+            .input(2)
+            .hidden(4, Activation::Tanh)
+            .output(1, Activation::Sigmoid)
+            .random(&mut rng)
+            .unwrap();
+
+        let dataset = vec![
+            (vec![0.0, 0.0], vec![0.0]),
+            (vec![0.0, 1.0], vec![1.0]),
+            (vec![1.0, 0.0], vec![1.0]),
+            (vec![1.0, 1.0], vec![0.0]),
+        ];
+
+        let loss = Trainer::new(Adam::new(0.05))
+            .batch_size(4)
+            .epochs(1000)
+            .train(&mut rng, &mut network, &dataset);
+
+        assert!(loss < 0.01, "loss = {}", loss);
+    }
+}
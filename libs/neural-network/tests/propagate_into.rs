@@ -0,0 +1,70 @@
+//! Checks that `Network::propagate_into()` doesn't allocate - lives in its own
+//! test binary, since it has to replace the global allocator.
+
+use lib_neural_network::{Activation, Network};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    /// Counted per thread, so that the test harness allocating in the
+    /// background doesn't get blamed on the network
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count_allocation() {
+    // Fails only while the thread is being torn down, at which point nobody's
+    // counting anymore
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+}
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn propagate_into_does_not_allocate() {
+    let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+    let network = Network::builder()
+        .input(9)
+        .hidden(18, Activation::Relu)
+        .hidden(4, Activation::Sigmoid)
+        .output(2, Activation::Softmax)
+        .random(&mut rng)
+        .unwrap();
+
+    let inputs: Vec<_> = (0..9).map(|i| i as f32 / 9.0).collect();
+    let mut scratch = network.scratch();
+    let mut out = [0.0; 2];
+
+    let before = allocations();
+
+    for _ in 0..100 {
+        network.propagate_into(&inputs, &mut scratch, &mut out);
+    }
+
+    assert_eq!(allocations(), before);
+}
@@ -0,0 +1,188 @@
+use crate::*;
+use lib_neural_network::{Network, NetworkBuilder};
+use nalgebra as na;
+use rand::{Rng, RngCore};
+
+#[derive(Clone, Debug)]
+pub struct Animal {
+    pub(crate) position: na::Point2<f32>,
+    pub(crate) rotation: na::Rotation2<f32>,
+    pub(crate) speed: f32,
+    pub(crate) eye: Eye,
+    pub(crate) brain: Network,
+
+    /// Mutation step size carried over between generations, for
+    /// self-adaptive mutation
+    pub(crate) step_size: Option<f32>,
+
+    /// Number of foods eaten by this animal
+    pub(crate) satiation: usize,
+}
+
+impl Animal {
+    pub fn random(rng: &mut dyn RngCore, config: &Config) -> Self {
+        let brain = Self::topology(config)
+            .random(rng)
+            .unwrap_or_else(|err| panic!("couldn't create brain: {}", err));
+
+        Self::new(rng, config, brain, None)
+    }
+
+    pub(crate) fn from_chromosome(
+        rng: &mut dyn RngCore,
+        config: &Config,
+        chromosome: &ga::Chromosome,
+    ) -> Self {
+        let brain = chromosome
+            .to_network(Self::topology(config).topology())
+            .unwrap_or_else(|err| panic!("couldn't create brain: {}", err));
+
+        Self::new(rng, config, brain, chromosome.step_size())
+    }
+
+    fn new(rng: &mut dyn RngCore, config: &Config, brain: Network, step_size: Option<f32>) -> Self {
+        Self {
+            position: na::Point2::new(rng.gen(), rng.gen()),
+            rotation: na::Rotation2::new(rng.gen_range(-PI..PI)),
+            speed: config.animal.speed_max,
+            eye: Eye::from(&config.eye),
+            brain,
+            step_size,
+            satiation: 0,
+        }
+    }
+
+    /// Brain gets vision on the input and responds with changes to speed and
+    /// rotation, both within `-1.0..=1.0`.
+    pub fn topology(config: &Config) -> NetworkBuilder {
+        let builder = Network::builder()
+            .initializer(config.brain.initializer)
+            .input(config.eye.cells);
+
+        config
+            .brain
+            .hidden_layers
+            .iter()
+            .fold(builder, |builder, layer| {
+                builder.hidden(layer.neurons, layer.activation)
+            })
+            .output(2, config.brain.output_activation)
+    }
+
+    pub fn position(&self) -> na::Point2<f32> {
+        self.position
+    }
+
+    pub fn rotation(&self) -> na::Rotation2<f32> {
+        self.rotation
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn eye(&self) -> &Eye {
+        &self.eye
+    }
+
+    pub fn brain(&self) -> &Network {
+        &self.brain
+    }
+
+    /// Returns mutation step size this animal's brain carries, if it's been
+    /// evolved with self-adaptive mutation.
+    pub fn step_size(&self) -> Option<f32> {
+        self.step_size
+    }
+
+    pub fn satiation(&self) -> usize {
+        self.satiation
+    }
+
+    pub(crate) fn process_brain(&mut self, config: &AnimalConfig, foods: &[Food]) {
+        let vision = self.eye.process_vision(self.position, self.rotation, foods);
+        let response = self.brain.propagate(vision);
+
+        let speed = response[0].clamp(-config.speed_accel, config.speed_accel);
+        let rotation = response[1].clamp(-config.rotation_accel, config.rotation_accel);
+
+        self.speed = (self.speed + speed).clamp(config.speed_min, config.speed_max);
+        self.rotation = na::Rotation2::new(self.rotation.angle() + rotation);
+    }
+
+    pub(crate) fn process_movement(&mut self) {
+        self.position += self.rotation * na::Vector2::new(0.0, self.speed);
+        self.position.x = self.position.x.rem_euclid(1.0);
+        self.position.y = self.position.y.rem_euclid(1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn animal(x: f32, y: f32, rotation: f32) -> Animal {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Animal {
+            position: na::Point2::new(x, y),
+            rotation: na::Rotation2::new(rotation),
+            ..Animal::random(&mut rng, &Config::default())
+        }
+    }
+
+    fn food(x: f32, y: f32) -> Food {
+        Food {
+            position: na::Point2::new(x, y),
+        }
+    }
+
+    #[test]
+    fn movement() {
+        let mut animal = animal(0.5, 0.5, 0.0);
+
+        animal.speed = 0.1;
+        animal.process_movement();
+
+        assert_relative_eq!(animal.position.x, 0.5);
+        assert_relative_eq!(animal.position.y, 0.6);
+
+        // Looking to the left (counter-clockwise)
+        animal.rotation = na::Rotation2::new(FRAC_PI_2);
+        animal.process_movement();
+
+        assert_relative_eq!(animal.position.x, 0.4);
+        assert_relative_eq!(animal.position.y, 0.6);
+    }
+
+    #[test]
+    fn movement_wraps_around() {
+        let mut animal = animal(0.95, 0.05, PI);
+
+        animal.speed = 0.1;
+        animal.process_movement();
+
+        assert_relative_eq!(animal.position.x, 0.95, epsilon = 1e-6);
+        assert_relative_eq!(animal.position.y, 0.95, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn process_brain() {
+        let mut animal = animal(0.5, 0.5, 0.0);
+
+        let config = AnimalConfig {
+            speed_min: 0.002,
+            speed_max: 0.003,
+            rotation_accel: 0.1,
+            ..Default::default()
+        };
+
+        animal.process_brain(&config, &[food(0.5, 0.9)]);
+
+        assert!((0.002..=0.003).contains(&animal.speed));
+        assert!(animal.rotation.angle().abs() <= 0.1 + 1e-6);
+    }
+}
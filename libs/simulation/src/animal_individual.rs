@@ -0,0 +1,45 @@
+use crate::*;
+
+/// Adapter between an [`Animal`] and the genetic algorithm - carries its
+/// brain's weights (together with the mutation step size) and how much food
+/// it ate.
+pub(crate) struct AnimalIndividual {
+    fitness: f32,
+    chromosome: ga::Chromosome,
+}
+
+impl AnimalIndividual {
+    pub(crate) fn from_animal(animal: &Animal) -> Self {
+        let mut chromosome = ga::Chromosome::from(&animal.brain);
+
+        if let Some(step_size) = animal.step_size {
+            chromosome.set_step_size(step_size);
+        }
+
+        Self {
+            fitness: animal.satiation as f32,
+            chromosome,
+        }
+    }
+
+    pub(crate) fn into_animal(self, rng: &mut dyn RngCore, config: &Config) -> Animal {
+        Animal::from_chromosome(rng, config, &self.chromosome)
+    }
+}
+
+impl ga::Individual for AnimalIndividual {
+    fn create(chromosome: ga::Chromosome) -> Self {
+        Self {
+            fitness: 0.0,
+            chromosome,
+        }
+    }
+
+    fn chromosome(&self) -> &ga::Chromosome {
+        &self.chromosome
+    }
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+}
@@ -0,0 +1,811 @@
+use lib_neural_network::{Activation, Initializer, NetworkError};
+use std::f32::consts::*;
+use std::fmt;
+
+/// Every tunable of a [`crate::Simulation`] run, e.g.:
+///
+/// ```toml
+/// seed = 42
+/// generations = 100
+///
+/// [world]
+/// animals = 40
+///
+/// [[brain.hidden_layers]]
+/// neurons = 18
+/// activation = { type = "relu" }
+///
+/// [ga]
+/// selection = { type = "tournament", size = 3 }
+/// mutation = { type = "gaussian", chance = 0.01, coeff = 0.3 }
+/// ```
+///
+/// Missing keys take their default values (see [`Config::default()`]).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Config {
+    /// Seed for the random number generator driving the whole run
+    pub seed: u64,
+
+    /// Number of generations to run for
+    pub generations: usize,
+
+    pub world: WorldConfig,
+    pub animal: AnimalConfig,
+    pub eye: EyeConfig,
+    pub brain: BrainConfig,
+    pub ga: GaConfig,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct WorldConfig {
+    /// Number of animals, i.e. size of the population
+    pub animals: usize,
+    pub foods: usize,
+
+    /// Number of steps each generation lives for, before being evolved
+    pub generation_length: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct AnimalConfig {
+    /// Minimum speed of an animal; stopping completely makes it easy to learn
+    /// to just stand still
+    pub speed_min: f32,
+
+    /// Maximum speed of an animal; going faster makes it fly over food
+    pub speed_max: f32,
+
+    /// How much can the brain change the speed in one step
+    pub speed_accel: f32,
+
+    /// How much can the brain change the rotation in one step, in radians
+    pub rotation_accel: f32,
+
+    /// How close an animal has to get to food to eat it
+    pub eat_radius: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct EyeConfig {
+    /// How far can an eye see, as a fraction of the world's size
+    pub fov_range: f32,
+
+    /// How wide can an eye see, in radians
+    pub fov_angle: f32,
+
+    /// Number of photoreceptors in a single eye
+    pub cells: usize,
+}
+
+/// Brain's input layer is always as large as the eye and its output layer
+/// always has two neurons (change of speed and of rotation).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct BrainConfig {
+    pub hidden_layers: Vec<LayerConfig>,
+
+    /// Activation of the output layer; outputs are clamped to the animal's
+    /// accelerations, so ranges wider than `-1.0..=1.0` are fine too
+    pub output_activation: Activation,
+
+    pub initializer: Initializer,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct LayerConfig {
+    pub neurons: usize,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activation: Activation,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct GaConfig {
+    pub selection: SelectionConfig,
+    pub crossover: CrossoverConfig,
+    pub mutation: MutationConfig,
+
+    /// Number of the fittest animals passed to the next generation as they are
+    pub elitism: usize,
+}
+
+/// See the corresponding selection methods in [`lib_genetic_algorithm`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum SelectionConfig {
+    #[default]
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    RouletteWheel,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    StochasticUniversalSampling,
+    Tournament {
+        size: usize,
+    },
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    Rank,
+    Truncation {
+        proportion: f32,
+    },
+}
+
+/// See the corresponding crossover methods in [`lib_genetic_algorithm`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum CrossoverConfig {
+    #[default]
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    Uniform,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    SinglePoint,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    TwoPoint,
+    Blend {
+        alpha: f32,
+    },
+    SimulatedBinary {
+        eta: f32,
+    },
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "unit_variant"))]
+    Neuron,
+}
+
+/// See the corresponding mutation methods in [`lib_genetic_algorithm`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum MutationConfig {
+    Gaussian {
+        chance: f32,
+        coeff: f32,
+
+        /// Factor of the 1/5th success rule, if enabled
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        one_fifth_rule: Option<f32>,
+    },
+    UniformReset {
+        chance: f32,
+        limit: f32,
+    },
+    SelfAdaptive {
+        chance: f32,
+        initial_step_size: f32,
+    },
+}
+
+impl Config {
+    /// Parses and validates configuration written in TOML.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        let config: Self = toml::from_str(toml).map_err(|err| ConfigError::Parse {
+            message: err.to_string(),
+        })?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Writes down the whole configuration, defaults included, so that it
+    /// can be loaded back with [`Config::from_toml()`].
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_else(|err| panic!("couldn't serialize config: {}", err))
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check(self.generations > 0, "generations", "must be positive")?;
+
+        check(self.world.animals > 0, "world.animals", "must be positive")?;
+
+        check(
+            self.world.generation_length > 0,
+            "world.generation_length",
+            "must be positive",
+        )?;
+
+        self.animal.validate()?;
+        self.eye.validate()?;
+        self.brain.validate()?;
+        self.ga.validate()?;
+
+        check(
+            self.ga.elitism < self.world.animals,
+            "ga.elitism",
+            "must be lower than world.animals",
+        )?;
+
+        Ok(())
+    }
+}
+
+impl AnimalConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        check(
+            is_non_negative(self.speed_min),
+            "animal.speed_min",
+            "must not be negative",
+        )?;
+
+        check(
+            is_non_negative(self.speed_max) && self.speed_max >= self.speed_min,
+            "animal.speed_max",
+            "must not be lower than animal.speed_min",
+        )?;
+
+        check(
+            is_non_negative(self.speed_accel),
+            "animal.speed_accel",
+            "must not be negative",
+        )?;
+
+        check(
+            is_non_negative(self.rotation_accel),
+            "animal.rotation_accel",
+            "must not be negative",
+        )?;
+
+        check(
+            is_non_negative(self.eat_radius),
+            "animal.eat_radius",
+            "must not be negative",
+        )
+    }
+}
+
+impl EyeConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        check(
+            is_positive(self.fov_range),
+            "eye.fov_range",
+            "must be positive",
+        )?;
+
+        check(
+            self.fov_angle > 0.0 && self.fov_angle <= 2.0 * PI,
+            "eye.fov_angle",
+            "must be within (0, 2π]",
+        )?;
+
+        check(self.cells > 0, "eye.cells", "must be positive")
+    }
+}
+
+impl BrainConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (idx, layer) in self.hidden_layers.iter().enumerate() {
+            let key = format!("brain.hidden_layers[{}]", idx);
+
+            check(
+                layer.neurons > 0,
+                &format!("{}.neurons", key),
+                "must be positive",
+            )?;
+
+            validate_activation(&layer.activation, &format!("{}.activation", key))?;
+        }
+
+        validate_activation(&self.output_activation, "brain.output_activation")?;
+
+        self.initializer.validate().map_err(|err| match err {
+            NetworkError::InvalidInitializer {
+                parameter, reason, ..
+            } => ConfigError::InvalidValue {
+                key: format!("brain.initializer.{}", parameter),
+                reason: reason.to_string(),
+            },
+
+            err => ConfigError::InvalidValue {
+                key: "brain.initializer".into(),
+                reason: err.to_string(),
+            },
+        })
+    }
+}
+
+impl GaConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        match self.selection {
+            SelectionConfig::Tournament { size } => {
+                check(size > 0, "ga.selection.size", "must be positive")?;
+            }
+
+            SelectionConfig::Truncation { proportion } => {
+                check(
+                    proportion > 0.0 && proportion <= 1.0,
+                    "ga.selection.proportion",
+                    "must be within (0, 1]",
+                )?;
+            }
+
+            _ => (),
+        }
+
+        match self.crossover {
+            CrossoverConfig::Blend { alpha } => {
+                check(
+                    is_non_negative(alpha),
+                    "ga.crossover.alpha",
+                    "must not be negative",
+                )?;
+            }
+
+            CrossoverConfig::SimulatedBinary { eta } => {
+                check(
+                    is_non_negative(eta),
+                    "ga.crossover.eta",
+                    "must not be negative",
+                )?;
+            }
+
+            _ => (),
+        }
+
+        let chance = match self.mutation {
+            MutationConfig::Gaussian {
+                chance,
+                coeff,
+                one_fifth_rule,
+            } => {
+                check(
+                    is_non_negative(coeff),
+                    "ga.mutation.coeff",
+                    "must not be negative",
+                )?;
+
+                if let Some(factor) = one_fifth_rule {
+                    check(
+                        factor > 0.0 && factor <= 1.0,
+                        "ga.mutation.one_fifth_rule",
+                        "must be within (0, 1]",
+                    )?;
+                }
+
+                chance
+            }
+
+            MutationConfig::UniformReset { chance, limit } => {
+                check(
+                    is_non_negative(limit),
+                    "ga.mutation.limit",
+                    "must not be negative",
+                )?;
+
+                chance
+            }
+
+            MutationConfig::SelfAdaptive {
+                chance,
+                initial_step_size,
+            } => {
+                check(
+                    is_positive(initial_step_size),
+                    "ga.mutation.initial_step_size",
+                    "must be positive",
+                )?;
+
+                chance
+            }
+        };
+
+        check(
+            (0.0..=1.0).contains(&chance),
+            "ga.mutation.chance",
+            "must be within [0, 1]",
+        )
+    }
+}
+
+fn validate_activation(activation: &Activation, key: &str) -> Result<(), ConfigError> {
+    if let Activation::LeakyRelu { alpha } = activation {
+        check(
+            alpha.is_finite(),
+            &format!("{}.alpha", key),
+            "must be finite",
+        )?;
+    }
+
+    Ok(())
+}
+
+fn check(ok: bool, key: &str, reason: &str) -> Result<(), ConfigError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(ConfigError::InvalidValue {
+            key: key.into(),
+            reason: reason.into(),
+        })
+    }
+}
+
+fn is_positive(value: f32) -> bool {
+    value > 0.0 && value.is_finite()
+}
+
+fn is_non_negative(value: f32) -> bool {
+    value >= 0.0 && value.is_finite()
+}
+
+/// Rejects keys given to a unit variant of the tagged enums above, which serde
+/// would otherwise ignore despite `deny_unknown_fields`.
+#[cfg(feature = "serde")]
+fn unit_variant<'de, D>(deserializer: D) -> Result<(), D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Empty {}
+
+    <Empty as serde::Deserialize>::deserialize(deserializer).map(|Empty {}| ())
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            generations: 100,
+            world: Default::default(),
+            animal: Default::default(),
+            eye: Default::default(),
+            brain: Default::default(),
+            ga: Default::default(),
+        }
+    }
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            animals: 40,
+            foods: 60,
+            generation_length: 2500,
+        }
+    }
+}
+
+impl Default for AnimalConfig {
+    fn default() -> Self {
+        Self {
+            speed_min: 0.001,
+            speed_max: 0.005,
+            speed_accel: 0.2,
+            rotation_accel: FRAC_PI_2,
+            eat_radius: 0.01,
+        }
+    }
+}
+
+impl Default for EyeConfig {
+    fn default() -> Self {
+        Self {
+            fov_range: 0.25,
+            fov_angle: PI + FRAC_PI_4,
+            cells: 9,
+        }
+    }
+}
+
+impl Default for BrainConfig {
+    fn default() -> Self {
+        Self {
+            hidden_layers: vec![LayerConfig {
+                neurons: 2 * EyeConfig::default().cells,
+                activation: Activation::Relu,
+            }],
+            output_activation: Activation::Tanh,
+            initializer: Initializer::default(),
+        }
+    }
+}
+
+impl Default for MutationConfig {
+    fn default() -> Self {
+        Self::Gaussian {
+            chance: 0.01,
+            coeff: 0.3,
+            one_fifth_rule: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// Configuration is not a valid TOML or doesn't match the schema; the
+    /// message points at the offending line and key
+    Parse {
+        message: String,
+    },
+
+    InvalidValue {
+        key: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { message } => {
+                write!(f, "couldn't parse config: {}", message)
+            }
+
+            Self::InvalidValue { key, reason } => {
+                write!(f, "invalid value of `{}`: {}", key, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_neural_network::{BiasInit, WeightInit};
+
+    fn error(f: impl FnOnce(&mut Config)) -> String {
+        let mut config = Config::default();
+
+        f(&mut config);
+        config.validate().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            error(|config| config.world.animals = 0),
+            "invalid value of `world.animals`: must be positive"
+        );
+
+        assert_eq!(
+            error(|config| config.animal.speed_max = 0.0),
+            "invalid value of `animal.speed_max`: must not be lower than animal.speed_min"
+        );
+
+        assert_eq!(
+            error(|config| config.eye.fov_angle = 7.0),
+            "invalid value of `eye.fov_angle`: must be within (0, 2π]"
+        );
+
+        assert_eq!(
+            error(|config| config.brain.hidden_layers.push(LayerConfig {
+                neurons: 0,
+                activation: Activation::Relu,
+            })),
+            "invalid value of `brain.hidden_layers[1].neurons`: must be positive"
+        );
+
+        assert_eq!(
+            error(
+                |config| config.brain.output_activation = Activation::LeakyRelu {
+                    alpha: f32::INFINITY
+                }
+            ),
+            "invalid value of `brain.output_activation.alpha`: must be finite"
+        );
+
+        assert_eq!(
+            error(|config| config.brain.initializer.biases = BiasInit::Uniform { limit: 0.0 }),
+            "invalid value of `brain.initializer.biases.limit`: must be positive"
+        );
+
+        assert_eq!(
+            error(|config| config.brain.initializer.weights =
+                WeightInit::Orthogonal { gain: f32::NAN }),
+            "invalid value of `brain.initializer.weights.gain`: must be finite"
+        );
+
+        assert_eq!(
+            error(|config| config.ga.selection = SelectionConfig::Tournament { size: 0 }),
+            "invalid value of `ga.selection.size`: must be positive"
+        );
+
+        assert_eq!(
+            error(|config| config.ga.crossover = CrossoverConfig::Blend { alpha: -1.0 }),
+            "invalid value of `ga.crossover.alpha`: must not be negative"
+        );
+
+        assert_eq!(
+            error(|config| config.ga.mutation = MutationConfig::UniformReset {
+                chance: f32::NAN,
+                limit: 1.0
+            }),
+            "invalid value of `ga.mutation.chance`: must be within [0, 1]"
+        );
+
+        assert_eq!(
+            error(|config| config.ga.elitism = 41),
+            "invalid value of `ga.elitism`: must be lower than world.animals"
+        );
+
+        // Nobody would be left to evolve
+        assert_eq!(
+            error(|config| config.ga.elitism = 40),
+            "invalid value of `ga.elitism`: must be lower than world.animals"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    mod toml {
+        use super::*;
+
+        fn error(toml: &str) -> String {
+            Config::from_toml(toml).unwrap_err().to_string()
+        }
+
+        #[test]
+        fn defaults() {
+            assert_eq!(Config::from_toml(""), Ok(Config::default()));
+
+            let config = Config::from_toml(
+                r#"
+                seed = 42
+
+                [world]
+                animals = 10
+
+                [[brain.hidden_layers]]
+                neurons = 4
+
+                [[brain.hidden_layers]]
+                neurons = 3
+                activation = { type = "leaky_relu", alpha = 0.1 }
+
+                [brain.initializer]
+                weights = { type = "he_normal" }
+
+                [ga]
+                selection = { type = "tournament", size = 3 }
+                mutation = { type = "gaussian", chance = 0.1, coeff = 0.2, one_fifth_rule = 0.9 }
+                "#,
+            )
+            .unwrap();
+
+            assert_eq!(config.seed, 42);
+            assert_eq!(config.world.animals, 10);
+            assert_eq!(config.world.foods, WorldConfig::default().foods);
+            assert_eq!(config.eye, EyeConfig::default());
+
+            assert_eq!(
+                config.brain.hidden_layers,
+                [
+                    LayerConfig {
+                        neurons: 4,
+                        activation: Activation::Relu,
+                    },
+                    LayerConfig {
+                        neurons: 3,
+                        activation: Activation::LeakyRelu { alpha: 0.1 },
+                    },
+                ]
+            );
+
+            assert_eq!(config.brain.initializer.weights, WeightInit::HeNormal);
+            assert_eq!(config.brain.initializer.biases, BiasInit::default());
+            assert_eq!(config.ga.selection, SelectionConfig::Tournament { size: 3 });
+            assert_eq!(config.ga.crossover, CrossoverConfig::Uniform);
+
+            assert_eq!(
+                config.ga.mutation,
+                MutationConfig::Gaussian {
+                    chance: 0.1,
+                    coeff: 0.2,
+                    one_fifth_rule: Some(0.9),
+                }
+            );
+        }
+
+        #[test]
+        fn roundtrip() {
+            let mut config = Config {
+                seed: 1234,
+                ..Default::default()
+            };
+
+            config.brain.initializer.biases = BiasInit::Constant { value: 0.1 };
+            config.ga.crossover = CrossoverConfig::SimulatedBinary { eta: 2.0 };
+            config.ga.mutation = MutationConfig::SelfAdaptive {
+                chance: 0.05,
+                initial_step_size: 0.1,
+            };
+
+            assert_eq!(Config::from_toml(&config.to_toml()), Ok(config));
+        }
+
+        #[test]
+        fn parse_errors_point_at_the_key() {
+            let actual = error("[eye]\ncels = 9");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("unknown field `cels`"), "{}", actual);
+
+            let actual = error("[world]\nanimals = \"many\"");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("animals"), "{}", actual);
+
+            let actual = error("[ga]\nselection = { type = \"lottery\" }");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("unknown variant `lottery`"), "{}", actual);
+
+            let actual = error("[brain]\noutput_activation = { type = \"tanh\", alpha = 0.3 }");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("unknown field `alpha`"), "{}", actual);
+
+            let actual =
+                error("[brain.initializer]\nweights = { type = \"he_normal\", limit = 3.0 }");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("unknown field `limit`"), "{}", actual);
+
+            let actual = error("[ga]\nselection = { type = \"rank\", size = 3 }");
+
+            assert!(actual.contains("line 2"), "{}", actual);
+            assert!(actual.contains("unknown field `size`"), "{}", actual);
+        }
+
+        #[test]
+        fn invalid_values() {
+            assert_eq!(
+                error("[ga]\nselection = { type = \"truncation\", proportion = 2.0 }"),
+                "invalid value of `ga.selection.proportion`: must be within (0, 1]"
+            );
+        }
+    }
+}
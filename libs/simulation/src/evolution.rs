@@ -0,0 +1,107 @@
+//! Glue between [`GaConfig`] and [`lib_genetic_algorithm`].
+
+use crate::*;
+use lib_neural_network::LayerTopology;
+
+pub(crate) enum Selection {
+    RouletteWheel(ga::RouletteWheelSelection),
+    StochasticUniversalSampling(ga::StochasticUniversalSampling),
+    Tournament(ga::TournamentSelection),
+    Rank(ga::RankSelection),
+    Truncation(ga::TruncationSelection),
+}
+
+impl ga::SelectionMethod for Selection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: ga::Individual,
+    {
+        match self {
+            Self::RouletteWheel(method) => method.select(rng, population),
+            Self::StochasticUniversalSampling(method) => method.select(rng, population),
+            Self::Tournament(method) => method.select(rng, population),
+            Self::Rank(method) => method.select(rng, population),
+            Self::Truncation(method) => method.select(rng, population),
+        }
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: ga::Individual,
+    {
+        match self {
+            Self::RouletteWheel(method) => method.select_many(rng, population, count),
+            Self::StochasticUniversalSampling(method) => method.select_many(rng, population, count),
+            Self::Tournament(method) => method.select_many(rng, population, count),
+            Self::Rank(method) => method.select_many(rng, population, count),
+            Self::Truncation(method) => method.select_many(rng, population, count),
+        }
+    }
+}
+
+impl GaConfig {
+    /// Builds the genetic algorithm for brains of given topology.
+    pub(crate) fn build(&self, topology: &[LayerTopology]) -> ga::GeneticAlgorithm<Selection> {
+        let selection = match self.selection {
+            SelectionConfig::RouletteWheel => Selection::RouletteWheel(ga::RouletteWheelSelection),
+
+            SelectionConfig::StochasticUniversalSampling => {
+                Selection::StochasticUniversalSampling(ga::StochasticUniversalSampling)
+            }
+
+            SelectionConfig::Tournament { size } => {
+                Selection::Tournament(ga::TournamentSelection::new(size))
+            }
+
+            SelectionConfig::Rank => Selection::Rank(ga::RankSelection),
+
+            SelectionConfig::Truncation { proportion } => {
+                Selection::Truncation(ga::TruncationSelection::new(proportion))
+            }
+        };
+
+        let crossover: Box<dyn ga::CrossoverMethod> = match self.crossover {
+            CrossoverConfig::Uniform => Box::new(ga::UniformCrossover),
+            CrossoverConfig::SinglePoint => Box::new(ga::SinglePointCrossover),
+            CrossoverConfig::TwoPoint => Box::new(ga::TwoPointCrossover),
+            CrossoverConfig::Blend { alpha } => Box::new(ga::BlendCrossover::new(alpha)),
+
+            CrossoverConfig::SimulatedBinary { eta } => {
+                Box::new(ga::SimulatedBinaryCrossover::new(eta))
+            }
+
+            CrossoverConfig::Neuron => Box::new(ga::NeuronCrossover::new(topology)),
+        };
+
+        let mutation: Box<dyn ga::MutationMethod> = match self.mutation {
+            MutationConfig::Gaussian {
+                chance,
+                coeff,
+                one_fifth_rule,
+            } => {
+                let method = ga::GaussianMutation::new(chance, coeff);
+
+                match one_fifth_rule {
+                    Some(factor) => Box::new(method.with_one_fifth_rule(factor)),
+                    None => Box::new(method),
+                }
+            }
+
+            MutationConfig::UniformReset { chance, limit } => {
+                Box::new(ga::UniformResetMutation::new(chance, limit))
+            }
+
+            MutationConfig::SelfAdaptive {
+                chance,
+                initial_step_size,
+            } => Box::new(ga::SelfAdaptiveMutation::new(chance, initial_step_size)),
+        };
+
+        ga::GeneticAlgorithm::new(selection, crossover, mutation).with_elitism(self.elitism)
+    }
+}
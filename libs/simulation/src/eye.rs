@@ -0,0 +1,198 @@
+use crate::*;
+
+/// Splits the field of view into equal-width cells, ordered from the right to
+/// the left edge, each sensing how close the food it sees is.
+#[derive(Clone, Debug)]
+pub struct Eye {
+    fov_range: f32,
+    fov_angle: f32,
+    cells: usize,
+}
+
+impl Eye {
+    pub fn new(fov_range: f32, fov_angle: f32, cells: usize) -> Self {
+        assert!(fov_range > 0.0);
+        assert!(fov_angle > 0.0);
+        assert!(cells > 0);
+
+        Self {
+            fov_range,
+            fov_angle,
+            cells,
+        }
+    }
+
+    pub fn fov_range(&self) -> f32 {
+        self.fov_range
+    }
+
+    pub fn fov_angle(&self) -> f32 {
+        self.fov_angle
+    }
+
+    pub fn cells(&self) -> usize {
+        self.cells
+    }
+
+    /// Returns energy of each cell - the closer the food, the higher the
+    /// energy, from 0.0 for food at (or beyond) the range up to 1.0 for food
+    /// right in front of the eye; energies of foods seen by the same cell add
+    /// up.
+    pub fn process_vision(
+        &self,
+        position: na::Point2<f32>,
+        rotation: na::Rotation2<f32>,
+        foods: &[Food],
+    ) -> Vec<f32> {
+        let mut cells = vec![0.0; self.cells];
+
+        for food in foods {
+            let vec = world::offset(position, food.position);
+            let dist = vec.norm();
+
+            if dist >= self.fov_range {
+                continue;
+            }
+
+            // Angle between where the eye looks and the food, positive
+            // counter-clockwise (to the left)
+            let angle = na::Rotation2::rotation_between(&na::Vector2::y(), &vec).angle();
+            let angle = na::wrap(angle - rotation.angle(), -PI, PI);
+
+            if angle < -self.fov_angle / 2.0 || angle > self.fov_angle / 2.0 {
+                continue;
+            }
+
+            let cell = (angle + self.fov_angle / 2.0) / self.fov_angle;
+            let cell = (cell * self.cells as f32) as usize;
+            let cell = cell.min(self.cells - 1);
+
+            cells[cell] += (self.fov_range - dist) / self.fov_range;
+        }
+
+        cells
+    }
+}
+
+impl From<&EyeConfig> for Eye {
+    fn from(config: &EyeConfig) -> Self {
+        Self::new(config.fov_range, config.fov_angle, config.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn food(x: f32, y: f32) -> Food {
+        Food {
+            position: na::Point2::new(x, y),
+        }
+    }
+
+    /// Looks up (towards +y) from the middle of the world, with a 90° field of
+    /// view split into 5 cells.
+    fn vision(foods: &[Food]) -> Vec<f32> {
+        Eye::new(0.25, FRAC_PI_2, 5).process_vision(
+            na::Point2::new(0.5, 0.5),
+            na::Rotation2::new(0.0),
+            foods,
+        )
+    }
+
+    #[test]
+    fn straight_ahead() {
+        let actual = vision(&[food(0.5, 0.6)]);
+
+        assert_relative_eq!(
+            actual.as_slice(),
+            [0.0, 0.0, 0.6, 0.0, 0.0].as_ref(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn energies_add_up() {
+        let actual = vision(&[food(0.5, 0.6), food(0.5, 0.7)]);
+
+        assert_relative_eq!(
+            actual.as_slice(),
+            [0.0, 0.0, 0.8, 0.0, 0.0].as_ref(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn behind() {
+        assert_eq!(vision(&[food(0.5, 0.4)]), [0.0; 5]);
+
+        // ... unless the eye sees all around
+        let actual = Eye::new(0.25, 2.0 * PI, 4).process_vision(
+            na::Point2::new(0.5, 0.5),
+            na::Rotation2::new(0.0),
+            &[food(0.5, 0.4)],
+        );
+
+        assert_eq!(actual.iter().filter(|&&energy| energy > 0.0).count(), 1);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(vision(&[food(0.5, 0.8)]), [0.0; 5]);
+        assert_eq!(vision(&[food(0.5, 0.75)]), [0.0; 5]);
+    }
+
+    #[test]
+    fn fov_boundary() {
+        // 44° to the left and to the right; right-most cell comes first
+        let y = 0.5 + 0.1 * 44f32.to_radians().cos();
+        let x = 0.1 * 44f32.to_radians().sin();
+
+        let left = vision(&[food(0.5 - x, y)]);
+        let right = vision(&[food(0.5 + x, y)]);
+
+        assert!(left[4] > 0.0);
+        assert_eq!(&left[..4], [0.0; 4]);
+        assert!(right[0] > 0.0);
+        assert_eq!(&right[1..], [0.0; 4]);
+
+        // 46° to the left and to the right
+        let y = 0.5 + 0.1 * 46f32.to_radians().cos();
+        let x = 0.1 * 46f32.to_radians().sin();
+
+        assert_eq!(vision(&[food(0.5 - x, y)]), [0.0; 5]);
+        assert_eq!(vision(&[food(0.5 + x, y)]), [0.0; 5]);
+    }
+
+    #[test]
+    fn across_the_edge() {
+        let actual = Eye::new(0.25, FRAC_PI_2, 5).process_vision(
+            na::Point2::new(0.5, 0.95),
+            na::Rotation2::new(0.0),
+            &[food(0.5, 0.05)],
+        );
+
+        assert_relative_eq!(
+            actual.as_slice(),
+            [0.0, 0.0, 0.6, 0.0, 0.0].as_ref(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        // Looking to the left (towards -x), food at the left edge of the world
+        let actual = Eye::new(0.25, FRAC_PI_2, 5).process_vision(
+            na::Point2::new(0.5, 0.5),
+            na::Rotation2::new(FRAC_PI_2),
+            &[food(0.4, 0.5)],
+        );
+
+        assert_relative_eq!(
+            actual.as_slice(),
+            [0.0, 0.0, 0.6, 0.0, 0.0].as_ref(),
+            epsilon = 1e-6
+        );
+    }
+}
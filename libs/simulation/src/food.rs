@@ -0,0 +1,19 @@
+use nalgebra as na;
+use rand::{Rng, RngCore};
+
+#[derive(Clone, Debug)]
+pub struct Food {
+    pub(crate) position: na::Point2<f32>,
+}
+
+impl Food {
+    pub fn random(rng: &mut dyn RngCore) -> Self {
+        Self {
+            position: na::Point2::new(rng.gen(), rng.gen()),
+        }
+    }
+
+    pub fn position(&self) -> na::Point2<f32> {
+        self.position
+    }
+}
@@ -0,0 +1,366 @@
+mod animal;
+mod animal_individual;
+mod config;
+mod evolution;
+mod eye;
+mod food;
+mod statistics;
+mod world;
+
+pub use self::{animal::*, config::*, eye::*, food::*, statistics::*, world::*};
+use self::{animal_individual::*, evolution::*};
+use lib_genetic_algorithm as ga;
+use lib_neural_network::Network;
+use nalgebra as na;
+use rand::RngCore;
+use std::f32::consts::*;
+
+pub struct Simulation {
+    config: Config,
+    world: World,
+    ga: ga::GeneticAlgorithm<Selection>,
+
+    /// Number of steps the current generation has lived for
+    age: usize,
+    generation: usize,
+
+    /// Brain of the fittest animal of the previous generation
+    champion: Option<Network>,
+}
+
+impl Simulation {
+    pub fn random(rng: &mut dyn RngCore) -> Self {
+        Self::random_with(rng, &Config::default())
+    }
+
+    pub fn random_with(rng: &mut dyn RngCore, config: &Config) -> Self {
+        Self::try_random_with(rng, config)
+            .unwrap_or_else(|err| panic!("couldn't create simulation: {}", err))
+    }
+
+    pub fn try_random_with(rng: &mut dyn RngCore, config: &Config) -> Result<Self, ConfigError> {
+        config.validate()?;
+
+        let ga = config.ga.build(Animal::topology(config).topology());
+
+        Ok(Self {
+            config: config.clone(),
+            world: World::random(rng, config),
+            ga,
+            age: 0,
+            generation: 0,
+            champion: None,
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns brain of the fittest animal of the previous generation, if
+    /// there was one.
+    pub fn champion(&self) -> Option<&Network> {
+        self.champion.as_ref()
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Performs a single step - a single second, so to say - of our
+    /// simulation; once the current generation has lived long enough,
+    /// evolves it and returns its statistics.
+    pub fn step(&mut self, rng: &mut dyn RngCore) -> Option<Statistics> {
+        self.process_collisions(rng);
+        self.process_brains();
+        self.process_movements();
+
+        self.age += 1;
+
+        if self.age >= self.config.world.generation_length {
+            Some(self.evolve(rng))
+        } else {
+            None
+        }
+    }
+
+    /// Fast-forwards until the end of the current generation.
+    pub fn train(&mut self, rng: &mut dyn RngCore) -> Statistics {
+        loop {
+            if let Some(statistics) = self.step(rng) {
+                return statistics;
+            }
+        }
+    }
+
+    fn process_collisions(&mut self, rng: &mut dyn RngCore) {
+        for animal in &mut self.world.animals {
+            for food in &mut self.world.foods {
+                let distance = world::offset(animal.position, food.position).norm();
+
+                if distance <= self.config.animal.eat_radius {
+                    animal.satiation += 1;
+                    *food = Food::random(rng);
+                }
+            }
+        }
+    }
+
+    fn process_brains(&mut self) {
+        for animal in &mut self.world.animals {
+            animal.process_brain(&self.config.animal, &self.world.foods);
+        }
+    }
+
+    fn process_movements(&mut self) {
+        for animal in &mut self.world.animals {
+            animal.process_movement();
+        }
+    }
+
+    fn evolve(&mut self, rng: &mut dyn RngCore) -> Statistics {
+        let population: Vec<_> = self
+            .world
+            .animals
+            .iter()
+            .map(AnimalIndividual::from_animal)
+            .collect();
+
+        let statistics = Statistics::new(self.generation, ga::Statistics::new(&population));
+
+        self.champion = self
+            .world
+            .animals
+            .iter()
+            .max_by_key(|animal| animal.satiation)
+            .map(|animal| animal.brain.clone());
+
+        self.world.animals = self
+            .ga
+            .evolve(rng, &population)
+            .into_iter()
+            .map(|individual| individual.into_animal(rng, &self.config))
+            .collect();
+
+        for food in &mut self.world.foods {
+            *food = Food::random(rng);
+        }
+
+        self.age = 0;
+        self.generation += 1;
+
+        statistics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_neural_network::Activation;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let simulation = Simulation::random(&mut rng);
+
+        assert_eq!(simulation.world().animals().len(), 40);
+        assert_eq!(simulation.world().foods().len(), 60);
+        assert!(simulation.champion().is_none());
+    }
+
+    #[test]
+    fn random_with() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut config = Config::default();
+
+        config.world.animals = 3;
+        config.world.foods = 5;
+        config.eye.cells = 4;
+        config.brain.hidden_layers = vec![
+            LayerConfig {
+                neurons: 6,
+                activation: Activation::Relu,
+            },
+            LayerConfig {
+                neurons: 5,
+                activation: Activation::Sigmoid,
+            },
+        ];
+        config.ga.crossover = CrossoverConfig::Neuron;
+
+        let simulation = Simulation::random_with(&mut rng, &config);
+        let animal = &simulation.world().animals()[0];
+
+        assert_eq!(simulation.world().animals().len(), 3);
+        assert_eq!(simulation.world().foods().len(), 5);
+        assert_eq!(animal.eye().cells(), 4);
+
+        let topology: Vec<_> = animal
+            .brain()
+            .topology()
+            .iter()
+            .map(|layer| layer.output_neurons)
+            .collect();
+
+        assert_eq!(topology, [4, 6, 5, 2]);
+        assert_eq!(animal.brain().topology()[2].activation, Activation::Sigmoid);
+
+        config.world.animals = 0;
+
+        assert!(Simulation::try_random_with(&mut rng, &config).is_err());
+    }
+
+    #[test]
+    fn step() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+        let before = simulation.world().clone();
+
+        for _ in 0..10 {
+            assert_eq!(simulation.step(&mut rng), None);
+        }
+
+        for (animal, before) in simulation.world().animals().iter().zip(before.animals()) {
+            assert_ne!(animal.position(), before.position());
+            assert!((0.0..1.0).contains(&animal.position().x));
+            assert!((0.0..1.0).contains(&animal.position().y));
+            assert!((0.001..=0.005).contains(&animal.speed()));
+        }
+    }
+
+    #[test]
+    fn eating() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        let food = simulation.world.foods[0].position;
+        simulation.world.animals[0].position = food + na::Vector2::new(0.005, 0.0);
+
+        simulation.step(&mut rng);
+
+        assert_eq!(simulation.world().animals()[0].satiation(), 1);
+        assert_eq!(simulation.world().foods().len(), 60);
+        assert_ne!(simulation.world().foods()[0].position(), food);
+    }
+
+    #[test]
+    fn eating_across_the_edge() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        simulation.world.foods[0].position = na::Point2::new(0.001, 0.5);
+        simulation.world.animals[0].position = na::Point2::new(0.999, 0.5);
+
+        simulation.step(&mut rng);
+
+        assert_eq!(simulation.world().animals()[0].satiation(), 1);
+        assert_ne!(simulation.world().foods()[0].position().x, 0.001);
+    }
+
+    #[test]
+    fn train() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut config = Config::default();
+
+        config.world.generation_length = 100;
+
+        let mut simulation = Simulation::random_with(&mut rng, &config);
+
+        simulation.world.animals[0].satiation = 3;
+        simulation.world.animals[1].satiation = 1;
+
+        let before: Vec<_> = simulation
+            .world()
+            .animals()
+            .iter()
+            .map(|animal| animal.brain().to_weights())
+            .collect();
+
+        let champion = simulation.world().animals()[0].brain().to_weights();
+        let statistics = simulation.train(&mut rng);
+
+        assert_eq!(statistics.generation(), 0);
+        assert_eq!(simulation.champion().unwrap().to_weights(), champion);
+        assert!(statistics.max_fitness() >= 3.0);
+        assert!(statistics.min_fitness() <= statistics.median_fitness());
+        assert!(statistics.median_fitness() <= statistics.max_fitness());
+        assert_eq!(simulation.generation(), 1);
+
+        // Brains got evolved and everyone starts from scratch
+        let animals = simulation.world().animals();
+
+        assert_eq!(animals.len(), 40);
+        assert!(animals.iter().all(|animal| animal.satiation() == 0));
+
+        assert!(animals
+            .iter()
+            .zip(&before)
+            .any(|(animal, before)| animal.brain().to_weights() != *before));
+
+        assert_eq!(simulation.train(&mut rng).generation(), 1);
+    }
+
+    #[test]
+    fn train_with_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut config = Config::default();
+
+        config.world.generation_length = 10;
+        config.ga.selection = SelectionConfig::StochasticUniversalSampling;
+        config.ga.elitism = config.world.animals;
+
+        assert!(Simulation::try_random_with(&mut rng, &config).is_err());
+
+        config.ga.elitism = config.world.animals - 1;
+
+        let mut simulation = Simulation::random_with(&mut rng, &config);
+
+        simulation.world.animals[0].satiation = 1;
+        simulation.train(&mut rng);
+
+        assert_eq!(simulation.world().animals().len(), 40);
+    }
+
+    #[test]
+    fn train_keeps_step_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut config = Config::default();
+
+        config.world.generation_length = 10;
+        config.ga.mutation = MutationConfig::SelfAdaptive {
+            chance: 0.1,
+            initial_step_size: 0.01,
+        };
+
+        let mut simulation = Simulation::random_with(&mut rng, &config);
+
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| animal.step_size().is_none()));
+
+        simulation.train(&mut rng);
+
+        for animal in &mut simulation.world.animals {
+            assert!(animal.step_size().is_some());
+            animal.step_size = Some(0.5);
+        }
+
+        simulation.train(&mut rng);
+
+        // Had the step sizes been lost, everyone would start again from
+        // `initial_step_size`
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| (0.25..1.0).contains(&animal.step_size().unwrap())));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::*;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    generation: usize,
+    ga: ga::Statistics,
+}
+
+impl Statistics {
+    pub(crate) fn new(generation: usize, ga: ga::Statistics) -> Self {
+        Self { generation, ga }
+    }
+
+    /// Number of the generation these statistics describe, starting at zero.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn min_fitness(&self) -> f32 {
+        self.ga.min_fitness()
+    }
+
+    pub fn max_fitness(&self) -> f32 {
+        self.ga.max_fitness()
+    }
+
+    pub fn avg_fitness(&self) -> f32 {
+        self.ga.avg_fitness()
+    }
+
+    pub fn median_fitness(&self) -> f32 {
+        self.ga.median_fitness()
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "generation {}: {}", self.generation, self.ga)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Animal, Config, Food};
+use nalgebra as na;
+use rand::RngCore;
+
+#[derive(Clone, Debug)]
+pub struct World {
+    pub(crate) animals: Vec<Animal>,
+    pub(crate) foods: Vec<Food>,
+}
+
+impl World {
+    pub fn random(rng: &mut dyn RngCore, config: &Config) -> Self {
+        let animals = (0..config.world.animals)
+            .map(|_| Animal::random(rng, config))
+            .collect();
+
+        let foods = (0..config.world.foods).map(|_| Food::random(rng)).collect();
+
+        Self { animals, foods }
+    }
+
+    pub fn animals(&self) -> &[Animal] {
+        &self.animals
+    }
+
+    pub fn foods(&self) -> &[Food] {
+        &self.foods
+    }
+}
+
+/// Returns the shortest vector leading from `from` to `to` - since the world
+/// wraps around, it might go across the edges.
+pub(crate) fn offset(from: na::Point2<f32>, to: na::Point2<f32>) -> na::Vector2<f32> {
+    (to - from).map(|delta| (delta + 0.5).rem_euclid(1.0) - 0.5)
+}